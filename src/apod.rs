@@ -2,6 +2,7 @@ use std::error::Error;
 
 use crate::key;
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
 
 /// Base Client for ApodClient api
 #[derive(Debug, PartialEq)]
@@ -14,7 +15,7 @@ impl ApodClient {
     /// Constructor
     pub fn new() -> Self {
         ApodClient {
-            base_url: String::from("https://api.nasa.gov/planetary/apod?"),
+            base_url: String::from("https://api.nasa.gov/planetary/apod"),
             date: None,
         }
     }
@@ -29,9 +30,9 @@ impl ApodClient {
         let key: String = key::from_dotenv()?;
 
         if self.date.is_none() {
-            let url = format!("{}api_key={}", self.base_url, key);
-
-            println!("Url: {}", url);
+            let url = UrlBuilder::new(&self.base_url)
+                .param("api_key", key)
+                .build();
 
             let res: String = ureq::get(&url).call()?.into_string()?;
             let apod: Response = into_response(res.as_str())?;
@@ -39,7 +40,10 @@ impl ApodClient {
             Ok(apod)
         } else {
             let date = self.date.as_ref().unwrap();
-            let url = format!("{}date={}&api_key={}", self.base_url, date, key);
+            let url = UrlBuilder::new(&self.base_url)
+                .param("date", date)
+                .param("api_key", key)
+                .build();
 
             let res: String = ureq::get(&url).call()?.into_string()?;
             let apod: Response = into_response(res.as_str())?;
@@ -47,4 +51,4 @@ impl ApodClient {
             Ok(apod)
         }
     }
-}
\ No newline at end of file
+}
@@ -3,6 +3,7 @@ use std::error::Error;
 use crate::key;
 use crate::pretty::*;
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
 
 /// Base Client for Solar Flare API
 #[derive(Debug, PartialEq)]
@@ -14,7 +15,7 @@ impl SolarFlare {
     /// New Base Client
     pub fn new() -> Self {
         SolarFlare {
-            base_url: String::from("https://api.nasa.gov/DONKI/FLR?startDate="),
+            base_url: String::from("https://api.nasa.gov/DONKI/FLR"),
         }
     }
 
@@ -22,7 +23,11 @@ impl SolarFlare {
     pub fn query(&self, start: String, end: String) -> Result<Response, Box<dyn Error>> {
         let key: String = key::from_dotenv()?;
 
-        let url: String = format!("{}{}&endDate={}&api_key={}", self.base_url, start, end, key);
+        let url: String = UrlBuilder::new(&self.base_url)
+            .param("startDate", &start)
+            .param("endDate", &end)
+            .param("api_key", key)
+            .build();
         println!("Starting solar query from {}, to {}.", start, end);
 
         let res: String = ureq::get(&url).call()?.into_string()?;
@@ -42,7 +47,7 @@ impl GeoMagnetic {
     /// Create new Base Client
     pub fn new() -> Self {
         GeoMagnetic {
-            base_url: String::from("https://api.nasa.gov/DONKI/GST?startDate="),
+            base_url: String::from("https://api.nasa.gov/DONKI/GST"),
         }
     }
 
@@ -50,7 +55,11 @@ impl GeoMagnetic {
     pub fn query(&self, start: String, end: String) -> Result<Response, Box<dyn Error>> {
         let key: String = key::from_dotenv()?;
 
-        let url: String = format!("{}{}&endDate={}&api_key={}", self.base_url, start, end, key);
+        let url: String = UrlBuilder::new(&self.base_url)
+            .param("startDate", &start)
+            .param("endDate", &end)
+            .param("api_key", key)
+            .build();
         println!("Starting GeoMagnetic query from {}, to {}.", start, end);
 
         let res: String = ureq::get(&url).call()?.into_string()?;
@@ -86,14 +95,18 @@ impl CoronalMassEjection {
     /// Create a new Base Client
     pub fn new() -> Self {
         CoronalMassEjection {
-            base_url: String::from("https://api.nasa.gov/DONKI/CME?startDate="),
+            base_url: String::from("https://api.nasa.gov/DONKI/CME"),
         }
     }
     /// Query method
     pub fn query(&self, start: String, end: String) -> Result<String, Box<dyn Error>> {
         let key = key::from_dotenv()?;
 
-        let url = format!("{}{}&endDate={}&api_key={}", self.base_url, start, end, key);
+        let url = UrlBuilder::new(&self.base_url)
+            .param("startDate", &start)
+            .param("endDate", &end)
+            .param("api_key", key)
+            .build();
         println!("Starting CME query from {}, to {}.", start, end);
 
         let res: String = ureq::get(&url).call()?.into_string()?;
@@ -113,17 +126,18 @@ impl SolarEnergeticParticle {
     /// Create a new SEP base client
     pub fn new() -> Self {
         SolarEnergeticParticle {
-            base_url: String::from("https://api.nasa.gov/DONKI/SEP?")
+            base_url: String::from("https://api.nasa.gov/DONKI/SEP")
         }
     }
     /// Query method
     pub fn query(&self, start: String, end: String) -> Result<String, Box<dyn Error>> {
         let key = key::from_dotenv()?;
 
-        let url = format!(
-            "{}startDate={}&endDate={}&api_key={}",
-            self.base_url, start, end, key
-        );
+        let url = UrlBuilder::new(&self.base_url)
+            .param("startDate", &start)
+            .param("endDate", &end)
+            .param("api_key", key)
+            .build();
 
         let res = ureq::get(&url).call()?.into_string()?;
         let sep = to_string_pretty(res).unwrap();
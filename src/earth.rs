@@ -0,0 +1,271 @@
+use std::error::Error;
+use std::io::Read;
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use serde_derive::Deserialize;
+use serde_json::Value as JsonValue;
+
+use crate::key;
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the Earth API, Landsat 8 imagery of a point.
+///
+/// A location must be set before querying; the date defaults to the most recent imagery.
+#[derive(Debug, PartialEq)]
+pub struct EarthClient {
+    base_url: String,
+    lat: Option<f64>,
+    lon: Option<f64>,
+    dim: Option<f64>,
+    date: Option<String>,
+    cloud_score: bool,
+}
+
+impl EarthClient {
+    pub fn new() -> Self {
+        EarthClient {
+            base_url: String::from("https://api.nasa.gov/planetary/earth"),
+            lat: None,
+            lon: None,
+            dim: None,
+            date: None,
+            cloud_score: false,
+        }
+    }
+
+    /// Center of the image, in degrees
+    pub fn location(&mut self, lat: f64, lon: f64) {
+        self.lat = Some(lat);
+        self.lon = Some(lon);
+    }
+
+    /// Width and height of the image in degrees, 0.025 by default
+    pub fn dim(&mut self, dim: f64) {
+        self.dim = Some(dim)
+    }
+
+    /// Date of the imagery, formatted YYYY-MM-DD. The closest available image is returned.
+    pub fn date(&mut self, date: &str) {
+        self.date = Some(String::from(date))
+    }
+
+    /// Asks for the percentage of the image covered by clouds
+    pub fn cloud_score(&mut self, cloud_score: bool) {
+        self.cloud_score = cloud_score
+    }
+
+    fn builder(&self, endpoint: &str, date: Option<&str>) -> Result<UrlBuilder, Box<dyn Error>> {
+        let (lat, lon) = match (self.lat, self.lon) {
+            (Some(lat), Some(lon)) => (lat, lon),
+            _ => return Err("a location is required".into()),
+        };
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(format!("{}, {} is not a valid location", lat, lon).into());
+        }
+
+        let mut url = UrlBuilder::new(&format!("{}/{}", self.base_url, endpoint));
+        url.param("lon", lon).param("lat", lat);
+        if let Some(date) = date {
+            NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map_err(|_| format!("{} is not a YYYY-MM-DD date", date))?;
+            url.param("date", date);
+        }
+        match self.dim {
+            Some(dim) if dim <= 0.0 => return Err("dim must be positive".into()),
+            Some(dim) => {
+                url.param("dim", dim);
+            }
+            None => {}
+        }
+        Ok(url)
+    }
+
+    /// Imagery url, without the api key
+    pub fn imagery_url(&self) -> Result<String, Box<dyn Error>> {
+        Ok(self.imagery_builder(self.date.as_deref())?.build())
+    }
+
+    fn imagery_builder(&self, date: Option<&str>) -> Result<UrlBuilder, Box<dyn Error>> {
+        let mut url = self.builder("imagery", date)?;
+        if self.cloud_score {
+            url.param("cloud_score", "True");
+        }
+        Ok(url)
+    }
+
+    /// Assets url, without the api key
+    pub fn assets_url(&self) -> Result<String, Box<dyn Error>> {
+        Ok(self.builder("assets", self.date.as_deref())?.build())
+    }
+
+    fn with_key(mut url: UrlBuilder) -> Result<String, Box<dyn Error>> {
+        let key: String = key::from_dotenv()?;
+        Ok(url.param("api_key", key).build())
+    }
+
+    /// Earth: assets of the location and date
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.query_assets(self.date.as_deref())
+    }
+
+    fn query_assets(&self, date: Option<&str>) -> Result<Response, Box<dyn Error>> {
+        let url = Self::with_key(self.builder("assets", date)?)?;
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let earth = into_response(res.as_str())?;
+
+        Ok(earth)
+    }
+
+    /// Landsat scenes of the location closest to the date
+    pub fn assets(&self) -> Result<Vec<Asset>, Box<dyn Error>> {
+        Asset::from_response(&self.query()?)
+    }
+
+    /// Image of the location and date, as png bytes
+    pub fn image(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        self.download(self.date.as_deref())
+    }
+
+    /// Image of an asset, as png bytes
+    pub fn image_of(&self, asset: &Asset) -> Result<Vec<u8>, Box<dyn Error>> {
+        self.download(Some(&asset.datetime()?.format("%Y-%m-%d").to_string()))
+    }
+
+    fn download(&self, date: Option<&str>) -> Result<Vec<u8>, Box<dyn Error>> {
+        let url = Self::with_key(self.imagery_builder(date)?)?;
+
+        let mut bytes = Vec::new();
+        ureq::get(&url)
+            .call()?
+            .into_reader()
+            .read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+
+    /// Closest asset to the middle of each month from `start` to `end`, for a time-lapse.
+    /// Months without a scene of their own are skipped, so every asset is different.
+    /// # Example
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use voyager_client::earth::*;
+    ///
+    /// let mut base = EarthClient::new();
+    /// base.location(29.78, -95.33);
+    /// base.dim(0.1);
+    ///
+    /// let start = NaiveDate::from_ymd(2018, 1, 1);
+    /// let end = NaiveDate::from_ymd(2018, 12, 31);
+    /// for (i, asset) in base.time_lapse(start, end).unwrap().iter().enumerate() {
+    ///     std::fs::write(format!("frame_{:02}.png", i), base.image_of(asset).unwrap()).unwrap();
+    /// }
+    /// ```
+    pub fn time_lapse(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<Asset>, Box<dyn Error>> {
+        let mut frames = Vec::new();
+        for target in months(start, end)? {
+            let date = target.format("%Y-%m-%d").to_string();
+            let assets = Asset::from_response(&self.query_assets(Some(&date))?)?;
+            if let Some(asset) = closest_in_month(&assets, target) {
+                frames.push(asset.clone());
+            }
+        }
+
+        Ok(frames)
+    }
+}
+
+impl Default for EarthClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The 15th of every month from `start` to `end`, both months included.
+pub fn months(start: NaiveDate, end: NaiveDate) -> Result<Vec<NaiveDate>, Box<dyn Error>> {
+    if start > end {
+        return Err("start is after end".into());
+    }
+
+    let mut targets = Vec::new();
+    let (mut year, mut month) = (start.year(), start.month());
+    while (year, month) <= (end.year(), end.month()) {
+        targets.push(NaiveDate::from_ymd_opt(year, month, 15).ok_or("date is out of range")?);
+        if month == 12 {
+            year += 1;
+            month = 1;
+        } else {
+            month += 1;
+        }
+    }
+
+    Ok(targets)
+}
+
+/// Asset closest to the target date, if one was acquired in the target's month.
+pub fn closest_in_month(assets: &[Asset], target: NaiveDate) -> Option<&Asset> {
+    assets
+        .iter()
+        .filter_map(|asset| Some((asset, asset.datetime().ok()?.date())))
+        .filter(|(_, date)| date.year() == target.year() && date.month() == target.month())
+        .min_by_key(|(_, date)| (*date - target).num_days().abs())
+        .map(|(asset, _)| asset)
+}
+
+/// A Landsat scene.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Asset {
+    /// Scene id, e.g. LC8_L1T_TOA/LC81270592014035LGN00
+    pub id: String,
+    /// Acquisition time (UTC), e.g. 2014-02-04T03:30:01.210000
+    pub date: String,
+    /// Url of the scene's image, if the API gave one
+    pub url: Option<String>,
+}
+
+impl Asset {
+    /// Parses an assets response, either a list of `results` or a single asset. The API's
+    /// "no assets found" message means there are none; any other body, such as an api key or
+    /// rate limit error, is returned as an error.
+    pub fn from_response(res: &Response) -> Result<Vec<Asset>, Box<dyn Error>> {
+        let json = res.json()?;
+        if json.get("results").is_some() {
+            return res.field("results");
+        }
+        if json.get("id").is_some() {
+            return Ok(vec![serde_json::from_value(json)?]);
+        }
+
+        let msg = json
+            .get("msg")
+            .and_then(JsonValue::as_str)
+            .unwrap_or_default();
+        let lower = msg.to_lowercase();
+        if lower.starts_with("no ") && lower.contains("asset") {
+            return Ok(Vec::new());
+        }
+        let error = json
+            .get("error")
+            .and_then(|error| error.get("message").or_else(|| error.get("code")))
+            .and_then(JsonValue::as_str)
+            .unwrap_or(msg);
+        if error.is_empty() {
+            Err(format!("response has no assets: {}", json).into())
+        } else {
+            Err(format!("Earth API error: {}", error).into())
+        }
+    }
+
+    /// Acquisition time (UTC)
+    pub fn datetime(&self) -> Result<NaiveDateTime, Box<dyn Error>> {
+        Ok(NaiveDateTime::parse_from_str(
+            &self.date,
+            "%Y-%m-%dT%H:%M:%S%.f",
+        )?)
+    }
+}
@@ -0,0 +1,390 @@
+use std::error::Error;
+
+use chrono::NaiveDateTime;
+use serde_derive::Deserialize;
+use serde_json::Value as JsonValue;
+
+use crate::response::*;
+use crate::url_builder::{encode, UrlBuilder};
+
+/// Base Client for the EONET v3 API (Earth Observatory Natural Event Tracker).
+///
+/// The filters apply to the events and GeoJSON queries. Categories and sources take a single
+/// id or a comma separated list.
+#[derive(Debug, PartialEq)]
+pub struct EonetClient {
+    base_url: String,
+    status: Option<EventStatus>,
+    category: Option<String>,
+    source: Option<String>,
+    bbox: Option<[f64; 4]>,
+    days: Option<u32>,
+    start: Option<String>,
+    end: Option<String>,
+    limit: Option<u32>,
+    magnitude: Option<(String, Option<f64>, Option<f64>)>,
+}
+
+/// Which events to return, by whether they are still ongoing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EventStatus {
+    Open,
+    Closed,
+    All,
+}
+
+impl EventStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EventStatus::Open => "open",
+            EventStatus::Closed => "closed",
+            EventStatus::All => "all",
+        }
+    }
+}
+
+impl EonetClient {
+    pub fn new() -> Self {
+        EonetClient {
+            base_url: String::from("https://eonet.gsfc.nasa.gov/api/v3"),
+            status: None,
+            category: None,
+            source: None,
+            bbox: None,
+            days: None,
+            start: None,
+            end: None,
+            limit: None,
+            magnitude: None,
+        }
+    }
+
+    /// Only open (the API default), closed or all events
+    pub fn status(&mut self, status: EventStatus) {
+        self.status = Some(status)
+    }
+
+    /// Category ids, e.g. wildfires or severeStorms,volcanoes
+    pub fn category(&mut self, category: &str) {
+        self.category = Some(String::from(category))
+    }
+
+    /// Source ids, e.g. InciWeb or InciWeb,EO
+    pub fn source(&mut self, source: &str) {
+        self.source = Some(String::from(source))
+    }
+
+    /// Bounding box, in degrees: upper left longitude and latitude, then lower right
+    /// longitude and latitude
+    pub fn bbox(&mut self, min_lon: f64, max_lat: f64, max_lon: f64, min_lat: f64) {
+        self.bbox = Some([min_lon, max_lat, max_lon, min_lat])
+    }
+
+    /// Events from the last number of days, including today
+    pub fn days(&mut self, days: u32) {
+        self.days = Some(days)
+    }
+
+    /// Events from this date on, formatted YYYY-MM-DD
+    pub fn start(&mut self, start: &str) {
+        self.start = Some(String::from(start))
+    }
+
+    /// Events up to this date, formatted YYYY-MM-DD
+    pub fn end(&mut self, end: &str) {
+        self.end = Some(String::from(end))
+    }
+
+    /// Maximum number of events returned
+    pub fn limit(&mut self, limit: u32) {
+        self.limit = Some(limit)
+    }
+
+    /// Events with a magnitude of the given id (see [`EonetClient::magnitudes`]), optionally
+    /// bounded
+    pub fn magnitude(&mut self, mag_id: &str, min: Option<f64>, max: Option<f64>) {
+        self.magnitude = Some((String::from(mag_id), min, max))
+    }
+
+    fn filters(&self, url: &mut UrlBuilder) -> Result<(), Box<dyn Error>> {
+        if self.days.is_some() && (self.start.is_some() || self.end.is_some()) {
+            return Err("days cannot be combined with start or end".into());
+        }
+        let date = |value: &Option<String>| -> Result<(), Box<dyn Error>> {
+            if let Some(value) = value {
+                chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                    .map_err(|_| format!("{} is not a YYYY-MM-DD date", value))?;
+            }
+            Ok(())
+        };
+        date(&self.start)?;
+        date(&self.end)?;
+        if let (Some(start), Some(end)) = (&self.start, &self.end) {
+            if start > end {
+                return Err(format!("start {} is after end {}", start, end).into());
+            }
+        }
+        if self.limit == Some(0) {
+            return Err("limit must be greater than zero".into());
+        }
+
+        if let Some(status) = self.status {
+            url.param("status", status.as_str());
+        }
+        if let Some(category) = &self.category {
+            url.param("category", category);
+        }
+        if let Some(source) = &self.source {
+            url.param("source", source);
+        }
+        if let Some([min_lon, max_lat, max_lon, min_lat]) = self.bbox {
+            let lon = -180.0..=180.0;
+            let lat = -90.0..=90.0;
+            if !lon.contains(&min_lon) || !lon.contains(&max_lon) {
+                return Err("bbox longitudes must be within -180 to 180".into());
+            }
+            if !lat.contains(&min_lat) || !lat.contains(&max_lat) || min_lat > max_lat {
+                return Err("bbox latitudes must be within -90 to 90, upper first".into());
+            }
+            url.param(
+                "bbox",
+                format!("{},{},{},{}", min_lon, max_lat, max_lon, min_lat),
+            );
+        }
+        if let Some(days) = self.days {
+            url.param("days", days);
+        }
+        if let Some(start) = &self.start {
+            url.param("start", start);
+        }
+        if let Some(end) = &self.end {
+            url.param("end", end);
+        }
+        if let Some(limit) = self.limit {
+            url.param("limit", limit);
+        }
+        if let Some((mag_id, min, max)) = &self.magnitude {
+            url.param("magID", mag_id);
+            if let Some(min) = min {
+                url.param("magMin", min);
+            }
+            if let Some(max) = max {
+                url.param("magMax", max);
+            }
+        }
+        Ok(())
+    }
+
+    /// Events url, including the filters
+    pub fn events_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&format!("{}/events", self.base_url));
+        self.filters(&mut url)?;
+        Ok(url.build())
+    }
+
+    /// GeoJSON events url, including the filters
+    pub fn geojson_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&format!("{}/events/geojson", self.base_url));
+        self.filters(&mut url)?;
+        Ok(url.build())
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let eonet = into_response(res.as_str())?;
+
+        Ok(eonet)
+    }
+
+    /// EONET: events matching the filters
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.events_url()?)
+    }
+
+    /// Events parsed into [`Event`] records
+    pub fn events(&self) -> Result<Vec<Event>, Box<dyn Error>> {
+        Event::from_response(&self.query()?)
+    }
+
+    /// Events matching the filters as a GeoJSON FeatureCollection
+    pub fn events_geojson(&self) -> Result<JsonValue, Box<dyn Error>> {
+        self.get(&self.geojson_url()?)?.json()
+    }
+
+    /// Every event category
+    pub fn categories(&self) -> Result<Vec<Category>, Box<dyn Error>> {
+        let res = self.get(&format!("{}/categories", self.base_url))?;
+        res.field("categories")
+    }
+
+    /// Every source events are reported by
+    pub fn sources(&self) -> Result<Vec<Source>, Box<dyn Error>> {
+        let res = self.get(&format!("{}/sources", self.base_url))?;
+        res.field("sources")
+    }
+
+    /// Imagery layers, for every category or a single one
+    pub fn layers(&self, category: Option<&str>) -> Result<Vec<LayerCategory>, Box<dyn Error>> {
+        let url = match category {
+            Some(category) => format!("{}/layers/{}", self.base_url, encode(category)),
+            None => format!("{}/layers", self.base_url),
+        };
+        self.get(&url)?.field("categories")
+    }
+
+    /// Every magnitude type events can be filtered by
+    pub fn magnitudes(&self) -> Result<Vec<Magnitude>, Box<dyn Error>> {
+        let res = self.get(&format!("{}/magnitudes", self.base_url))?;
+        res.field("magnitudes")
+    }
+}
+
+impl Default for EonetClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A natural event, with its location over time.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Event {
+    /// Event id, e.g. EONET_5980
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// API url of the event
+    pub link: String,
+    /// Date the event ended, `None` while it is ongoing
+    pub closed: Option<String>,
+    pub categories: Vec<EventCategory>,
+    pub sources: Vec<EventSource>,
+    /// Location of the event over time, oldest first
+    pub geometry: Vec<EventGeometry>,
+}
+
+impl Event {
+    /// Parses the events of an events response.
+    pub fn from_response(res: &Response) -> Result<Vec<Event>, Box<dyn Error>> {
+        res.field("events")
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.closed.is_none()
+    }
+
+    /// Most recent location of the event
+    pub fn latest(&self) -> Option<&EventGeometry> {
+        self.geometry.iter().max_by(|a, b| a.date.cmp(&b.date))
+    }
+
+    /// Point locations as a time series of (time, longitude, latitude)
+    pub fn track(&self) -> Vec<(NaiveDateTime, f64, f64)> {
+        self.geometry
+            .iter()
+            .filter_map(|geometry| match geometry.shape {
+                Shape::Point([lon, lat]) => Some((geometry.datetime()?, lon, lat)),
+                Shape::Polygon(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Category an event belongs to.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventCategory {
+    pub id: String,
+    pub title: String,
+}
+
+/// Source reporting an event, with the url of its report.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EventSource {
+    pub id: String,
+    pub url: String,
+}
+
+/// Location of an event at a point in time.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventGeometry {
+    /// Magnitude of the event at this time, e.g. wind speed for storms
+    pub magnitude_value: Option<f64>,
+    /// Unit of the magnitude, e.g. kts
+    pub magnitude_unit: Option<String>,
+    /// ISO 8601 time, e.g. 2022-01-03T00:00:00Z
+    pub date: String,
+    #[serde(flatten)]
+    pub shape: Shape,
+}
+
+impl EventGeometry {
+    /// The date as a UTC date and time
+    pub fn datetime(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(&self.date, "%Y-%m-%dT%H:%M:%SZ").ok()
+    }
+}
+
+/// GeoJSON geometry of an event location, with [longitude, latitude] coordinates.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", content = "coordinates")]
+pub enum Shape {
+    Point([f64; 2]),
+    /// Outer ring first, then any holes
+    Polygon(Vec<Vec<[f64; 2]>>),
+}
+
+/// An event category.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Category {
+    /// Category id used by the category filter, e.g. wildfires
+    pub id: String,
+    pub title: String,
+    pub link: Option<String>,
+    pub description: Option<String>,
+    /// API url of the category's layers
+    pub layers: Option<String>,
+}
+
+/// An organization reporting events.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Source {
+    /// Source id used by the source filter, e.g. InciWeb
+    pub id: String,
+    pub title: String,
+    /// Homepage of the source
+    pub source: Option<String>,
+    pub link: Option<String>,
+}
+
+/// The imagery layers of a category.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LayerCategory {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub layers: Vec<Layer>,
+}
+
+/// A web mapping service layer showing events of a category.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Layer {
+    pub name: String,
+    pub service_url: String,
+    /// Service type, e.g. WMTS_1_0_0
+    pub service_type_id: String,
+    /// Service parameters, e.g. TILEMATRIXSET and FORMAT
+    #[serde(default)]
+    pub parameters: Vec<JsonValue>,
+}
+
+/// A magnitude type events can carry.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Magnitude {
+    /// Magnitude id used by the magnitude filter, e.g. mag_kts
+    pub id: String,
+    pub name: String,
+    pub unit: String,
+    pub description: Option<String>,
+    pub link: Option<String>,
+}
@@ -0,0 +1,236 @@
+use std::error::Error;
+use std::io::Read;
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde_derive::Deserialize;
+
+use crate::key;
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the EPIC API (Earth Polychromatic Imaging Camera on DSCOVR).
+#[derive(Debug, PartialEq)]
+pub struct EpicClient {
+    base_url: String,
+    archive_url: String,
+    collection: EpicCollection,
+}
+
+/// Image collection, by processing.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EpicCollection {
+    /// Natural color images
+    Natural,
+    /// Color corrected images, enhancing land features
+    Enhanced,
+    /// UV aerosol index images
+    Aerosol,
+    /// Cloud fraction images
+    Cloud,
+}
+
+impl EpicCollection {
+    fn as_str(&self) -> &'static str {
+        match self {
+            EpicCollection::Natural => "natural",
+            EpicCollection::Enhanced => "enhanced",
+            EpicCollection::Aerosol => "aerosol",
+            EpicCollection::Cloud => "cloud",
+        }
+    }
+}
+
+/// Archive format of an image.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImageFormat {
+    /// Full resolution png
+    Png,
+    /// Half resolution jpg
+    Jpg,
+    /// Thumbnail jpg
+    Thumbs,
+}
+
+impl EpicClient {
+    pub fn new() -> Self {
+        EpicClient {
+            base_url: String::from("https://api.nasa.gov/EPIC/api"),
+            archive_url: String::from("https://epic.gsfc.nasa.gov/archive"),
+            collection: EpicCollection::Natural,
+        }
+    }
+
+    /// Switches collection, natural by default
+    pub fn collection(&mut self, collection: EpicCollection) {
+        self.collection = collection
+    }
+
+    fn get(&self, path: &str) -> Result<Response, Box<dyn Error>> {
+        let key: String = key::from_dotenv()?;
+
+        let url = UrlBuilder::new(&format!(
+            "{}/{}{}",
+            self.base_url,
+            self.collection.as_str(),
+            path
+        ))
+        .param("api_key", key)
+        .build();
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let epic = into_response(res.as_str())?;
+
+        Ok(epic)
+    }
+
+    /// EPIC: metadata of the most recent images
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.get("")
+    }
+
+    /// EPIC: metadata of the images of a date, formatted YYYY-MM-DD
+    pub fn query_date(&self, date: &str) -> Result<Response, Box<dyn Error>> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("{} is not a YYYY-MM-DD date", date))?;
+        self.get(&format!("/date/{}", date))
+    }
+
+    /// The most recent images
+    pub fn latest(&self) -> Result<Vec<EpicImage>, Box<dyn Error>> {
+        EpicImage::from_response(&self.query()?)
+    }
+
+    /// The images of a date, formatted YYYY-MM-DD
+    pub fn images(&self, date: &str) -> Result<Vec<EpicImage>, Box<dyn Error>> {
+        EpicImage::from_response(&self.query_date(date)?)
+    }
+
+    /// Every date with images in the collection
+    pub fn available_dates(&self) -> Result<Vec<NaiveDate>, Box<dyn Error>> {
+        let dates: Vec<String> = serde_json::from_value(self.get("/available")?.json()?)?;
+        dates
+            .iter()
+            .map(|date| Ok(NaiveDate::parse_from_str(date, "%Y-%m-%d")?))
+            .collect()
+    }
+
+    /// Archive url of an image, e.g.
+    /// `https://epic.gsfc.nasa.gov/archive/natural/2019/05/30/png/epic_1b_20190530011359.png`.
+    /// The public archive does not need an api key.
+    pub fn image_url(
+        &self,
+        image: &EpicImage,
+        format: ImageFormat,
+    ) -> Result<String, Box<dyn Error>> {
+        let date = image.datetime()?;
+        let (folder, extension) = match format {
+            ImageFormat::Png => ("png", "png"),
+            ImageFormat::Jpg => ("jpg", "jpg"),
+            ImageFormat::Thumbs => ("thumbs", "jpg"),
+        };
+        Ok(format!(
+            "{}/{}/{}/{}/{}.{}",
+            self.archive_url,
+            self.collection.as_str(),
+            date.format("%Y/%m/%d"),
+            folder,
+            image.image,
+            extension
+        ))
+    }
+
+    /// Downloads an image from the archive
+    pub fn download(
+        &self,
+        image: &EpicImage,
+        format: ImageFormat,
+    ) -> Result<Vec<u8>, Box<dyn Error>> {
+        let url = self.image_url(image, format)?;
+
+        let mut bytes = Vec::new();
+        ureq::get(&url)
+            .call()?
+            .into_reader()
+            .read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+}
+
+impl Default for EpicClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Metadata of an EPIC image.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EpicImage {
+    pub identifier: String,
+    pub caption: String,
+    /// Image name, used in the archive urls, e.g. epic_1b_20190530011359
+    pub image: String,
+    pub version: Option<String>,
+    /// Acquisition time (UTC), e.g. 2019-05-30 00:50:27
+    pub date: String,
+    /// Geographic point at the center of the image
+    pub centroid_coordinates: Coordinates,
+    /// Position of DSCOVR (km, J2000, geocentric)
+    pub dscovr_j2000_position: Position,
+    /// Position of the Moon (km, J2000, geocentric)
+    pub lunar_j2000_position: Position,
+    /// Position of the Sun (km, J2000, geocentric)
+    pub sun_j2000_position: Position,
+    /// Orientation of DSCOVR
+    pub attitude_quaternions: Quaternion,
+}
+
+impl EpicImage {
+    /// Parses the images of a metadata response.
+    pub fn from_response(res: &Response) -> Result<Vec<EpicImage>, Box<dyn Error>> {
+        Ok(serde_json::from_value(res.json()?)?)
+    }
+
+    /// Acquisition time (UTC)
+    pub fn datetime(&self) -> Result<NaiveDateTime, Box<dyn Error>> {
+        Ok(NaiveDateTime::parse_from_str(
+            &self.date,
+            "%Y-%m-%d %H:%M:%S",
+        )?)
+    }
+
+    /// Distance from DSCOVR to the Earth's center (km)
+    pub fn dscovr_distance(&self) -> f64 {
+        self.dscovr_j2000_position.norm()
+    }
+}
+
+/// Latitude and longitude, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Coordinates {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Geocentric J2000 position, in km.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Position {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Position {
+    pub fn norm(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+/// Attitude quaternion, scalar first.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Quaternion {
+    pub q0: f64,
+    pub q1: f64,
+    pub q2: f64,
+    pub q3: f64,
+}
@@ -0,0 +1,277 @@
+use std::error::Error;
+
+use serde_derive::Deserialize;
+
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Columns selected by the prebuilt queries, matching the fields of [`Planet`]
+const PLANET_COLUMNS: &str = "pl_name,hostname,discoverymethod,disc_year,disc_facility,\
+pl_orbper,pl_orbsmax,pl_rade,pl_bmasse,pl_eqt,st_teff,sy_dist,ra,dec";
+
+/// Base Client for the NASA Exoplanet Archive TAP (Table Access Protocol) sync endpoint.
+/// No api key is needed.
+///
+/// Queries are written in ADQL, e.g.
+/// `select pl_name, pl_rade from pscomppars where disc_year = 2016`.
+#[derive(Debug, PartialEq)]
+pub struct ExoplanetClient {
+    base_url: String,
+}
+
+/// Output format of a TAP query.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TapFormat {
+    Json,
+    Csv,
+}
+
+impl TapFormat {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TapFormat::Json => "json",
+            TapFormat::Csv => "csv",
+        }
+    }
+}
+
+impl ExoplanetClient {
+    pub fn new() -> Self {
+        ExoplanetClient {
+            base_url: String::from("https://exoplanetarchive.ipac.caltech.edu/TAP/sync"),
+        }
+    }
+
+    pub fn sync_url(&self, adql: &str, format: TapFormat) -> Result<String, Box<dyn Error>> {
+        let adql = adql.trim();
+        if adql.is_empty() {
+            return Err("query is empty".into());
+        }
+
+        Ok(UrlBuilder::new(&self.base_url)
+            .param("query", adql)
+            .param("format", format.as_str())
+            .build())
+    }
+
+    fn get(&self, adql: &str, format: TapFormat) -> Result<String, Box<dyn Error>> {
+        let url = self.sync_url(adql, format)?;
+        Ok(ureq::get(&url).call()?.into_string()?)
+    }
+
+    /// Exoplanet Archive: rows of an ADQL query, as a json array of objects
+    pub fn query(&self, adql: &str) -> Result<Response, Box<dyn Error>> {
+        into_response(&self.get(adql, TapFormat::Json)?)
+    }
+
+    /// Rows of an ADQL query, as csv text with a header line
+    pub fn query_csv(&self, adql: &str) -> Result<String, Box<dyn Error>> {
+        self.get(adql, TapFormat::Csv)
+    }
+
+    /// Rows of an ADQL query, deserialized into a type of your own. Fields are matched to
+    /// the selected column names; nullable columns should be `Option`s.
+    /// # Example
+    /// ```no_run
+    /// use serde_derive::Deserialize;
+    /// use voyager_client::exoplanet::*;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Radius {
+    ///     pl_name: String,
+    ///     pl_rade: Option<f64>,
+    /// }
+    ///
+    /// let base = ExoplanetClient::new();
+    /// let radii: Vec<Radius> = base
+    ///     .rows("select pl_name, pl_rade from pscomppars where disc_year = 2016")
+    ///     .unwrap();
+    /// ```
+    pub fn rows<T: serde::de::DeserializeOwned>(
+        &self,
+        adql: &str,
+    ) -> Result<Vec<T>, Box<dyn Error>> {
+        Ok(serde_json::from_value(self.query(adql)?.json()?)?)
+    }
+
+    /// Rows of an ADQL query, as a [`Table`] keeping the selected column order
+    pub fn table(&self, adql: &str) -> Result<Table, Box<dyn Error>> {
+        Table::from_csv(&self.query_csv(adql)?)
+    }
+
+    /// Planets of a host star, e.g. Kepler-11, from the composite parameters table
+    pub fn host_planets(&self, hostname: &str) -> Result<Vec<Planet>, Box<dyn Error>> {
+        self.rows(&host_query(hostname))
+    }
+
+    /// Planets found by a discovery method, e.g. Transit or Radial Velocity, optionally in a
+    /// single year
+    pub fn planets_by_discovery(
+        &self,
+        method: &str,
+        year: Option<u32>,
+    ) -> Result<Vec<Planet>, Box<dyn Error>> {
+        self.rows(&discovery_query(method, year))
+    }
+}
+
+impl Default for ExoplanetClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// ADQL of [`ExoplanetClient::host_planets`]
+pub fn host_query(hostname: &str) -> String {
+    format!(
+        "select {} from pscomppars where hostname = {} order by pl_orbper",
+        PLANET_COLUMNS,
+        quote(hostname)
+    )
+}
+
+/// ADQL of [`ExoplanetClient::planets_by_discovery`]
+pub fn discovery_query(method: &str, year: Option<u32>) -> String {
+    let mut adql = format!(
+        "select {} from pscomppars where discoverymethod = {}",
+        PLANET_COLUMNS,
+        quote(method)
+    );
+    if let Some(year) = year {
+        adql.push_str(&format!(" and disc_year = {}", year));
+    }
+    adql.push_str(" order by disc_year, pl_name");
+    adql
+}
+
+/// Quotes an ADQL string literal, doubling single quotes
+fn quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// A planet of the composite parameters table (pscomppars), one row per planet.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Planet {
+    pub pl_name: String,
+    pub hostname: String,
+    pub discoverymethod: String,
+    pub disc_year: Option<u32>,
+    pub disc_facility: Option<String>,
+    /// Orbital period (days)
+    pub pl_orbper: Option<f64>,
+    /// Semi-major axis (au)
+    pub pl_orbsmax: Option<f64>,
+    /// Radius (Earth radii)
+    pub pl_rade: Option<f64>,
+    /// Mass or minimum mass (Earth masses)
+    pub pl_bmasse: Option<f64>,
+    /// Equilibrium temperature (K)
+    pub pl_eqt: Option<f64>,
+    /// Stellar effective temperature (K)
+    pub st_teff: Option<f64>,
+    /// Distance to the system (pc)
+    pub sy_dist: Option<f64>,
+    /// Right ascension (deg)
+    pub ra: Option<f64>,
+    /// Declination (deg)
+    pub dec: Option<f64>,
+}
+
+/// Query results without a schema, columns in the order they were selected.
+/// Empty cells are `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Option<String>>>,
+}
+
+impl Table {
+    /// Parses csv results, quoted fields included.
+    pub fn from_csv(csv: &str) -> Result<Table, Box<dyn Error>> {
+        let mut lines = parse_csv(csv)?.into_iter();
+        let columns = lines.next().ok_or("results have no header")?;
+
+        let rows = lines
+            .enumerate()
+            .map(|(i, line)| {
+                if line.len() != columns.len() {
+                    return Err(format!(
+                        "row {} has {} fields, expected {}",
+                        i + 1,
+                        line.len(),
+                        columns.len()
+                    )
+                    .into());
+                }
+                Ok(line
+                    .into_iter()
+                    .map(|cell| if cell.is_empty() { None } else { Some(cell) })
+                    .collect())
+            })
+            .collect::<Result<Vec<_>, Box<dyn Error>>>()?;
+
+        Ok(Table { columns, rows })
+    }
+
+    /// Index of a column
+    pub fn column_index(&self, column: &str) -> Option<usize> {
+        self.columns.iter().position(|c| c == column)
+    }
+
+    /// Cell of a row, `None` if the column is unknown or the cell is empty
+    pub fn get(&self, row: usize, column: &str) -> Option<&str> {
+        let index = self.column_index(column)?;
+        self.rows.get(row)?.get(index)?.as_deref()
+    }
+
+    /// Numeric cell of a row
+    pub fn get_f64(&self, row: usize, column: &str) -> Option<f64> {
+        self.get(row, column)?.parse().ok()
+    }
+
+    /// Every cell of a column
+    pub fn column(&self, column: &str) -> Option<Vec<Option<&str>>> {
+        let index = self.column_index(column)?;
+        Some(
+            self.rows
+                .iter()
+                .map(|row| row.get(index).and_then(|cell| cell.as_deref()))
+                .collect(),
+        )
+    }
+}
+
+/// Splits csv text into records, following RFC 4180 quoting.
+fn parse_csv(csv: &str) -> Result<Vec<Vec<String>>, Box<dyn Error>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = csv.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => record.push(std::mem::take(&mut field)),
+            '\r' if !quoted => {}
+            '\n' if !quoted => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if quoted {
+        return Err("unterminated quoted field".into());
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+
+    Ok(records)
+}
@@ -0,0 +1,396 @@
+use std::error::Error;
+
+use serde_derive::Deserialize;
+use serde_json::Value as JsonValue;
+
+use crate::response::*;
+use crate::url_builder::{encode, UrlBuilder};
+
+/// Base Client for the NASA Image and Video Library API. No api key is needed.
+///
+/// Every search parameter is optional, but a search needs at least one of them.
+#[derive(Debug, PartialEq)]
+pub struct ImageLibraryClient {
+    base_url: String,
+    q: Option<String>,
+    center: Option<String>,
+    keywords: Vec<String>,
+    media_types: Vec<MediaType>,
+    year_start: Option<u32>,
+    year_end: Option<u32>,
+    nasa_id: Option<String>,
+    page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MediaType {
+    Image,
+    Video,
+    Audio,
+}
+
+impl MediaType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MediaType::Image => "image",
+            MediaType::Video => "video",
+            MediaType::Audio => "audio",
+        }
+    }
+}
+
+/// Rendition of an asset, as suffixed to its file names (e.g. `~orig.jpg`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Rendition {
+    Original,
+    Large,
+    Medium,
+    Small,
+    Thumb,
+    /// Video preview
+    Preview,
+    /// Video for mobile devices
+    Mobile,
+}
+
+impl Rendition {
+    fn suffix(&self) -> &'static str {
+        match self {
+            Rendition::Original => "~orig.",
+            Rendition::Large => "~large.",
+            Rendition::Medium => "~medium.",
+            Rendition::Small => "~small.",
+            Rendition::Thumb => "~thumb.",
+            Rendition::Preview => "~preview.",
+            Rendition::Mobile => "~mobile.",
+        }
+    }
+}
+
+impl ImageLibraryClient {
+    pub fn new() -> Self {
+        ImageLibraryClient {
+            base_url: String::from("https://images-api.nasa.gov"),
+            q: None,
+            center: None,
+            keywords: Vec::new(),
+            media_types: Vec::new(),
+            year_start: None,
+            year_end: None,
+            nasa_id: None,
+            page: None,
+        }
+    }
+
+    /// Free text search terms
+    pub fn q(&mut self, q: &str) {
+        self.q = Some(String::from(q))
+    }
+
+    /// NASA center which published the media, e.g. JSC
+    pub fn center(&mut self, center: &str) {
+        self.center = Some(String::from(center))
+    }
+
+    pub fn keywords(&mut self, keywords: &[&str]) {
+        self.keywords = keywords.iter().map(|k| String::from(*k)).collect()
+    }
+
+    /// Restricts the search to the given media types, every type by default
+    pub fn media_types(&mut self, media_types: &[MediaType]) {
+        self.media_types = media_types.to_vec()
+    }
+
+    /// First year of creation, YYYY
+    pub fn year_start(&mut self, year: u32) {
+        self.year_start = Some(year)
+    }
+
+    /// Last year of creation, YYYY
+    pub fn year_end(&mut self, year: u32) {
+        self.year_end = Some(year)
+    }
+
+    /// Media asset's NASA ID, e.g. as11-40-5874
+    pub fn nasa_id(&mut self, nasa_id: &str) {
+        self.nasa_id = Some(String::from(nasa_id))
+    }
+
+    /// Page of results, starting at 1 (100 results per page)
+    pub fn page(&mut self, page: u32) {
+        self.page = Some(page)
+    }
+
+    pub fn search_url(&self) -> Result<String, Box<dyn Error>> {
+        if self.q.is_none()
+            && self.center.is_none()
+            && self.keywords.is_empty()
+            && self.media_types.is_empty()
+            && self.year_start.is_none()
+            && self.year_end.is_none()
+            && self.nasa_id.is_none()
+        {
+            return Err("a search needs at least one parameter".into());
+        }
+        for year in [self.year_start, self.year_end].iter().flatten() {
+            if !(1000..=9999).contains(year) {
+                return Err(format!("{} is not a YYYY year", year).into());
+            }
+        }
+        if let (Some(start), Some(end)) = (self.year_start, self.year_end) {
+            if start > end {
+                return Err("year_start is after year_end".into());
+            }
+        }
+
+        let mut url = UrlBuilder::new(&format!("{}/search", self.base_url));
+
+        if let Some(q) = &self.q {
+            url.param("q", q);
+        }
+        if let Some(center) = &self.center {
+            url.param("center", center);
+        }
+        if !self.keywords.is_empty() {
+            url.param("keywords", self.keywords.join(","));
+        }
+        if !self.media_types.is_empty() {
+            let types: Vec<&str> = self.media_types.iter().map(MediaType::as_str).collect();
+            url.param("media_type", types.join(","));
+        }
+        if let Some(year) = self.year_start {
+            url.param("year_start", year);
+        }
+        if let Some(year) = self.year_end {
+            url.param("year_end", year);
+        }
+        if let Some(nasa_id) = &self.nasa_id {
+            url.param("nasa_id", nasa_id);
+        }
+        match self.page {
+            Some(0) => return Err("pages start at 1".into()),
+            Some(page) => {
+                url.param("page", page);
+            }
+            None => {}
+        }
+
+        Ok(url.build())
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let library = into_response(res.as_str())?;
+
+        Ok(library)
+    }
+
+    /// Image and Video Library: search
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.search_url()?)
+    }
+
+    /// Searches the library and parses the results into a [`SearchResults`]
+    pub fn search(&self) -> Result<SearchResults, Box<dyn Error>> {
+        SearchResults::from_response(&self.query()?)
+    }
+
+    /// Items of an album, e.g. Apollo-at-50, on the given page (starting at 1)
+    pub fn album(&self, album: &str, page: u32) -> Result<SearchResults, Box<dyn Error>> {
+        if page == 0 {
+            return Err("pages start at 1".into());
+        }
+        let url = UrlBuilder::new(&format!("{}/album/{}", self.base_url, encode(album)))
+            .param("page", page)
+            .build();
+        SearchResults::from_response(&self.get(&url)?)
+    }
+
+    /// Every rendition of a media asset
+    pub fn asset(&self, nasa_id: &str) -> Result<Asset, Box<dyn Error>> {
+        Asset::from_response(&self.get(&self.id_url("asset", nasa_id))?)
+    }
+
+    /// Metadata of a media asset, such as its EXIF or file details
+    pub fn metadata(&self, nasa_id: &str) -> Result<JsonValue, Box<dyn Error>> {
+        let location = self.location("metadata", nasa_id)?;
+        self.get(&location)?.json()
+    }
+
+    /// Location of the captions file of a video asset (.srt or .vtt)
+    pub fn captions_location(&self, nasa_id: &str) -> Result<String, Box<dyn Error>> {
+        self.location("captions", nasa_id)
+    }
+
+    fn id_url(&self, endpoint: &str, nasa_id: &str) -> String {
+        format!("{}/{}/{}", self.base_url, endpoint, encode(nasa_id))
+    }
+
+    fn location(&self, endpoint: &str, nasa_id: &str) -> Result<String, Box<dyn Error>> {
+        let json = self.get(&self.id_url(endpoint, nasa_id))?.json()?;
+        Ok(json
+            .get("location")
+            .and_then(JsonValue::as_str)
+            .ok_or("response has no location")?
+            .to_string())
+    }
+}
+
+impl Default for ImageLibraryClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One page of search or album results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResults {
+    pub items: Vec<Item>,
+    /// Number of results across every page
+    pub total_hits: u64,
+    /// Url of the next page, if any
+    pub next: Option<String>,
+    /// Url of the previous page, if any
+    pub prev: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Collection {
+    #[serde(default)]
+    items: Vec<Item>,
+    metadata: Option<CollectionMetadata>,
+    #[serde(default)]
+    links: Vec<Link>,
+}
+
+#[derive(Deserialize)]
+struct CollectionMetadata {
+    total_hits: u64,
+}
+
+impl SearchResults {
+    /// Parses a search or album response.
+    pub fn from_response(res: &Response) -> Result<SearchResults, Box<dyn Error>> {
+        let collection: Collection = res.field("collection")?;
+        let link = |rel: &str| {
+            collection
+                .links
+                .iter()
+                .find(|link| link.rel == rel)
+                .map(|link| link.href.clone())
+        };
+
+        Ok(SearchResults {
+            total_hits: collection
+                .metadata
+                .as_ref()
+                .map_or(collection.items.len() as u64, |m| m.total_hits),
+            next: link("next"),
+            prev: link("prev"),
+            items: collection.items,
+        })
+    }
+}
+
+/// A media asset in search results.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Item {
+    /// Url of the asset's collection.json, listing its files
+    pub href: String,
+    #[serde(default)]
+    pub data: Vec<ItemData>,
+    /// Previews of the asset, such as its thumbnail
+    #[serde(default)]
+    pub links: Vec<Link>,
+}
+
+impl Item {
+    /// Description of the asset, the first (and usually only) entry of `data`
+    pub fn info(&self) -> Option<&ItemData> {
+        self.data.first()
+    }
+
+    /// Url of the preview image, if any
+    pub fn preview(&self) -> Option<&str> {
+        self.links
+            .iter()
+            .find(|link| link.rel == "preview")
+            .map(|link| link.href.as_str())
+    }
+}
+
+/// Description of a media asset.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ItemData {
+    pub nasa_id: String,
+    pub title: String,
+    /// image, video or audio
+    pub media_type: String,
+    pub center: Option<String>,
+    /// Creation time, e.g. 1969-07-20T00:00:00Z
+    pub date_created: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    #[serde(default)]
+    pub album: Vec<String>,
+    pub photographer: Option<String>,
+    pub secondary_creator: Option<String>,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Link {
+    pub href: String,
+    /// Relation of the link, e.g. preview, captions, next or prev
+    pub rel: String,
+    pub render: Option<String>,
+    pub prompt: Option<String>,
+}
+
+/// The files of a media asset.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Asset {
+    /// Urls of every file, renditions and metadata
+    pub files: Vec<String>,
+}
+
+impl Asset {
+    /// Parses an asset response.
+    pub fn from_response(res: &Response) -> Result<Asset, Box<dyn Error>> {
+        #[derive(Deserialize)]
+        struct File {
+            href: String,
+        }
+        #[derive(Deserialize)]
+        struct Files {
+            items: Vec<File>,
+        }
+        let files: Files = res.field("collection")?;
+
+        Ok(Asset {
+            files: files.items.into_iter().map(|file| file.href).collect(),
+        })
+    }
+
+    /// Url of a rendition, if the asset has it
+    pub fn rendition(&self, rendition: Rendition) -> Option<&str> {
+        self.files
+            .iter()
+            .find(|file| file.contains(rendition.suffix()))
+            .map(String::as_str)
+    }
+
+    /// Url of the largest rendition available, the original first
+    pub fn largest(&self) -> Option<&str> {
+        [
+            Rendition::Original,
+            Rendition::Large,
+            Rendition::Medium,
+            Rendition::Small,
+            Rendition::Thumb,
+        ]
+        .iter()
+        .find_map(|rendition| self.rendition(*rendition))
+    }
+}
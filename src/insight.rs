@@ -1,7 +1,11 @@
 use std::error::Error;
 
+use chrono::NaiveDateTime;
+use serde_json::Value as JsonValue;
+
 use crate::key;
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
 
 #[derive(Debug, PartialEq)]
 pub struct InsightWeather {
@@ -11,19 +15,232 @@ pub struct InsightWeather {
 impl InsightWeather {
     pub fn new() -> Self {
         InsightWeather {
-            base_url: String::from("https://api.nasa.gov/insight_weather/?api_key="),
+            base_url: String::from("https://api.nasa.gov/insight_weather/"),
         }
     }
 
     pub fn query(&self) -> Result<Response, Box<dyn Error>> {
         let key = key::from_dotenv()?;
 
-        let url = format!("{}{}&feedtype=json&ver=1.0", self.base_url, key);
-        println!("Starting Inisght Weather query: {}", url);
+        let url = UrlBuilder::new(&self.base_url)
+            .param("api_key", key)
+            .param("feedtype", "json")
+            .param("ver", "1.0")
+            .build();
 
         let res: String = ureq::get(&url).call()?.into_string()?;
-        let mrover = into_response(res.as_str()).unwrap();
+        let mrover = into_response(res.as_str())?;
 
         Ok(mrover)
     }
+
+    /// Queries the latest sols and parses them into an [`InsightSeries`]
+    pub fn weather(&self) -> Result<InsightSeries, Box<dyn Error>> {
+        InsightSeries::from_response(&self.query()?)
+    }
+}
+
+/// Daily summary of one sensor: average, sample count, minimum and maximum.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SensorSummary {
+    pub av: f64,
+    pub ct: u64,
+    pub mn: f64,
+    pub mx: f64,
+}
+
+impl SensorSummary {
+    fn from_json(json: &JsonValue) -> Option<SensorSummary> {
+        Some(SensorSummary {
+            av: json.get("av")?.as_f64()?,
+            ct: json.get("ct")?.as_u64()?,
+            mn: json.get("mn")?.as_f64()?,
+            mx: json.get("mx")?.as_f64()?,
+        })
+    }
+}
+
+/// One point of the wind direction compass rose.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WindDirection {
+    /// Direction the wind blows from, clockwise from north (deg)
+    pub compass_degrees: f64,
+    /// Compass point, e.g. NNE
+    pub compass_point: String,
+    /// East component of the unit vector pointing at the compass point
+    pub compass_right: f64,
+    /// North component of the unit vector pointing at the compass point
+    pub compass_up: f64,
+    /// Number of samples from this direction
+    pub ct: u64,
+}
+
+impl WindDirection {
+    fn from_json(json: &JsonValue) -> Option<WindDirection> {
+        Some(WindDirection {
+            compass_degrees: json.get("compass_degrees")?.as_f64()?,
+            compass_point: String::from(json.get("compass_point")?.as_str()?),
+            compass_right: json.get("compass_right")?.as_f64()?,
+            compass_up: json.get("compass_up")?.as_f64()?,
+            ct: json.get("ct")?.as_u64()?,
+        })
+    }
+}
+
+/// Weather summary of a single sol. Sensors whose validity check failed are `None`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolWeather {
+    pub sol: u32,
+    /// First UTC time of the sol's samples
+    pub first_utc: Option<NaiveDateTime>,
+    /// Last UTC time of the sol's samples
+    pub last_utc: Option<NaiveDateTime>,
+    /// Martian season at the lander, e.g. spring
+    pub season: Option<String>,
+    pub northern_season: Option<String>,
+    pub southern_season: Option<String>,
+    /// Atmospheric temperature (°C)
+    pub at: Option<SensorSummary>,
+    /// Horizontal wind speed (m/s)
+    pub hws: Option<SensorSummary>,
+    /// Atmospheric pressure (Pa)
+    pub pre: Option<SensorSummary>,
+    /// Wind direction compass rose, one entry per compass point with samples
+    pub wd: Vec<WindDirection>,
+    /// Compass point with the most samples
+    pub wd_most_common: Option<WindDirection>,
+}
+
+impl SolWeather {
+    fn from_json(sol: u32, json: &JsonValue, validity: Option<&JsonValue>) -> SolWeather {
+        // a sensor is dropped only when its validity check explicitly fails
+        let valid = |sensor: &str| {
+            validity
+                .and_then(|checks| checks.get(sensor))
+                .and_then(|check| check.get("valid"))
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(true)
+        };
+        let sensor = |name: &str| {
+            if valid(name) {
+                json.get(name).and_then(SensorSummary::from_json)
+            } else {
+                None
+            }
+        };
+        let text = |key: &str| json.get(key).and_then(JsonValue::as_str).map(String::from);
+        let utc = |key: &str| {
+            NaiveDateTime::parse_from_str(json.get(key)?.as_str()?, "%Y-%m-%dT%H:%M:%SZ").ok()
+        };
+
+        let (wd, wd_most_common) = match json.get("WD").and_then(JsonValue::as_object) {
+            Some(rose) if valid("WD") => {
+                let mut points: Vec<WindDirection> = rose
+                    .iter()
+                    .filter(|(key, _)| key.as_str() != "most_common")
+                    .filter_map(|(_, point)| WindDirection::from_json(point))
+                    .collect();
+                points.sort_by(|a, b| a.compass_degrees.total_cmp(&b.compass_degrees));
+                let most_common = rose.get("most_common").and_then(WindDirection::from_json);
+                (points, most_common)
+            }
+            _ => (Vec::new(), None),
+        };
+
+        SolWeather {
+            sol,
+            first_utc: utc("First_UTC"),
+            last_utc: utc("Last_UTC"),
+            season: text("Season"),
+            northern_season: text("Northern_season"),
+            southern_season: text("Southern_season"),
+            at: sensor("AT"),
+            hws: sensor("HWS"),
+            pre: sensor("PRE"),
+            wd,
+            wd_most_common,
+        }
+    }
+
+    /// True when at least one sensor has valid data
+    pub fn has_data(&self) -> bool {
+        self.at.is_some() || self.hws.is_some() || self.pre.is_some() || !self.wd.is_empty()
+    }
+}
+
+/// The sols of an InSight weather response, in `sol_keys` order, with the validity checks
+/// applied. Sols without any valid sensor are dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InsightSeries {
+    pub sols: Vec<SolWeather>,
+}
+
+impl InsightSeries {
+    /// Parses an InSight weather response.
+    pub fn from_response(res: &Response) -> Result<InsightSeries, Box<dyn Error>> {
+        let json = res.json()?;
+        let validity = json.get("validity_checks");
+
+        let mut sols = Vec::new();
+        for key in json
+            .get("sol_keys")
+            .and_then(JsonValue::as_array)
+            .ok_or("response has no sol_keys")?
+        {
+            let key = key.as_str().ok_or("sol key is not a string")?;
+            let sol = key.parse().map_err(|_| format!("{} is not a sol", key))?;
+            let data = json.get(key).ok_or(format!("sol {} has no data", key))?;
+
+            let weather = SolWeather::from_json(sol, data, validity.and_then(|v| v.get(key)));
+            if weather.has_data() {
+                sols.push(weather);
+            }
+        }
+
+        Ok(InsightSeries { sols })
+    }
+
+    /// Exports the series as CSV, one row per sol, with empty cells for missing values.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("sol,first_utc,last_utc,season");
+        for sensor in ["at", "hws", "pre"].iter() {
+            for stat in ["av", "mn", "mx", "ct"].iter() {
+                csv.push_str(&format!(",{}_{}", sensor, stat));
+            }
+        }
+        csv.push_str(",wd_most_common\n");
+
+        for sol in &self.sols {
+            let utc = |time: Option<NaiveDateTime>| {
+                time.map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string())
+                    .unwrap_or_default()
+            };
+            let mut row = vec![
+                sol.sol.to_string(),
+                utc(sol.first_utc),
+                utc(sol.last_utc),
+                sol.season.clone().unwrap_or_default(),
+            ];
+            for sensor in [&sol.at, &sol.hws, &sol.pre].iter() {
+                match sensor {
+                    Some(s) => row.extend(vec![
+                        s.av.to_string(),
+                        s.mn.to_string(),
+                        s.mx.to_string(),
+                        s.ct.to_string(),
+                    ]),
+                    None => row.extend(vec![String::new(); 4]),
+                }
+            }
+            row.push(
+                sol.wd_most_common
+                    .as_ref()
+                    .map(|wd| wd.compass_point.clone())
+                    .unwrap_or_default(),
+            );
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
 }
@@ -1,12 +1,143 @@
+use std::collections::BTreeMap;
 use std::error::Error;
 
+use chrono::{Datelike, NaiveDate, NaiveDateTime};
+use serde_json::{json, Value as JsonValue};
+
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+mod horizons;
+mod mdesign;
+mod nhats;
+mod periodic_orbits;
+mod porkchop;
+mod sb_ident;
+mod sbdb;
+mod scout;
+mod sentry;
+pub use horizons::*;
+pub use mdesign::*;
+pub use nhats::*;
+pub use periodic_orbits::*;
+pub use porkchop::*;
+pub use sb_ident::*;
+pub use sbdb::*;
+pub use scout::*;
+pub use sentry::*;
+
+/// Zips a JPL `fields`/`data` payload into one JSON object per row. Payloads whose `data`
+/// rows are already objects are returned as is, and a missing `data` array (count 0) yields
+/// no rows.
+pub(crate) fn records(json: &JsonValue) -> Result<Vec<JsonValue>, Box<dyn Error>> {
+    let data = match json.get("data").and_then(JsonValue::as_array) {
+        Some(data) => data,
+        None => return Ok(Vec::new()),
+    };
+    let fields = match json.get("fields").and_then(JsonValue::as_array) {
+        Some(fields) => fields,
+        None => return Ok(data.clone()),
+    };
+
+    let mut rows = Vec::with_capacity(data.len());
+    for row in data {
+        let values = row.as_array().ok_or("data row is not an array")?;
+        let mut record = serde_json::Map::new();
+        for (field, value) in fields.iter().zip(values) {
+            let field = field.as_str().ok_or("field name is not a string")?;
+            record.insert(field.to_string(), value.clone());
+        }
+        rows.push(JsonValue::Object(record));
+    }
+    Ok(rows)
+}
+
+/// Turns an `error` member of a JPL payload into an error.
+pub(crate) fn check_error(json: &JsonValue) -> Result<(), Box<dyn Error>> {
+    match json.get("error") {
+        Some(JsonValue::String(message)) => Err(message.clone().into()),
+        Some(error) => Err(error.to_string().into()),
+        None => Ok(()),
+    }
+}
+
+/// Reads a numeric field that JPL may send either as a string or as a number.
+pub(crate) fn field_f64(record: &JsonValue, key: &str) -> Option<f64> {
+    match record.get(key)? {
+        JsonValue::String(s) => s.trim().parse().ok(),
+        JsonValue::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+/// Reads a string field, treating JSON null as absent.
+pub(crate) fn field_str(record: &JsonValue, key: &str) -> Option<String> {
+    match record.get(key)? {
+        JsonValue::String(s) => Some(s.clone()),
+        JsonValue::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
 
 /// Atmospheric Impact Data
+///
+/// Every filter of the Fireball API is optional. Filters are validated when the query url is
+/// built, so an inverted range or a malformed date is reported as an error instead of being
+/// sent to the API.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// // All located events above 1 kt since 2010, strongest first
+/// let mut base = FireballClient::new();
+/// base.date_min("2010-01-01");
+/// base.impact_e_min(1.0);
+/// base.req_loc(true);
+/// base.sort(FireballSort::ImpactE, true);
+///
+/// let fireballs = base.fireballs().unwrap();
+/// ```
 #[derive(Debug, PartialEq)]
 pub struct FireballClient {
     base_url: String,
     limit: Option<u32>,
+    date_min: Option<String>,
+    date_max: Option<String>,
+    energy_min: Option<f64>,
+    energy_max: Option<f64>,
+    impact_e_min: Option<f64>,
+    impact_e_max: Option<f64>,
+    vel_min: Option<f64>,
+    vel_max: Option<f64>,
+    alt_min: Option<f64>,
+    alt_max: Option<f64>,
+    req_loc: Option<bool>,
+    req_alt: Option<bool>,
+    req_vel: Option<bool>,
+    vel_comp: Option<bool>,
+    sort: Option<(FireballSort, bool)>,
+}
+
+/// Fields the Fireball API can sort its results by.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FireballSort {
+    Date,
+    Energy,
+    ImpactE,
+    Vel,
+    Alt,
+}
+
+impl FireballSort {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FireballSort::Date => "date",
+            FireballSort::Energy => "energy",
+            FireballSort::ImpactE => "impact-e",
+            FireballSort::Vel => "vel",
+            FireballSort::Alt => "alt",
+        }
+    }
 }
 
 impl FireballClient {
@@ -14,6 +145,21 @@ impl FireballClient {
         FireballClient {
             base_url: String::from("https://ssd-api.jpl.nasa.gov/fireball.api"),
             limit: None,
+            date_min: None,
+            date_max: None,
+            energy_min: None,
+            energy_max: None,
+            impact_e_min: None,
+            impact_e_max: None,
+            vel_min: None,
+            vel_max: None,
+            alt_min: None,
+            alt_max: None,
+            req_loc: None,
+            req_alt: None,
+            req_vel: None,
+            vel_comp: None,
+            sort: None,
         }
     }
 
@@ -21,244 +167,323 @@ impl FireballClient {
         self.limit = Some(limit)
     }
 
-    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
-        if self.limit.is_none() {
-            let url = format!("{}", self.base_url);
+    /// Exclude data earlier than this date, formatted YYYY-MM-DD or YYYY-MM-DDThh:mm:ss
+    pub fn date_min(&mut self, date: &str) {
+        self.date_min = Some(String::from(date))
+    }
 
-            let res: String = ureq::get(&url).call()?.into_string()?;
-            let fireball = into_response(res.as_str()).unwrap();
-            Ok(fireball)
-        } else {
-            let limit = self.limit.as_ref().unwrap();
+    /// Exclude data later than this date, formatted YYYY-MM-DD or YYYY-MM-DDThh:mm:ss
+    pub fn date_max(&mut self, date: &str) {
+        self.date_max = Some(String::from(date))
+    }
 
-            let url = format!("{}?limit={}", self.base_url, limit);
+    /// Exclude data with total radiated energy below this value (10^10 joules)
+    pub fn energy_min(&mut self, energy: f64) {
+        self.energy_min = Some(energy)
+    }
 
-            let res: String = ureq::get(&url).call()?.into_string()?;
-            let fireball = into_response(res.as_str()).unwrap();
+    /// Exclude data with total radiated energy above this value (10^10 joules)
+    pub fn energy_max(&mut self, energy: f64) {
+        self.energy_max = Some(energy)
+    }
 
-            Ok(fireball)
-        }
+    /// Exclude data with impact energy below this value (kilotons)
+    pub fn impact_e_min(&mut self, impact_e: f64) {
+        self.impact_e_min = Some(impact_e)
     }
-}
 
-/// Base Client for the JPL Mission Design API in Query Mode.
-/// # Example
-/// ```
-/// use voyager_client::jpl::*;
-///
-/// let mut base = MissionDesign::new();
-///
-/// base.query(QueryType::DES, "2012%20TC4").unwrap();
-/// ```
-///
+    /// Exclude data with impact energy above this value (kilotons)
+    pub fn impact_e_max(&mut self, impact_e: f64) {
+        self.impact_e_max = Some(impact_e)
+    }
 
-/// Mission Design in Q Mode (query)
-#[derive(Debug)]
-pub struct MissionDesign {
-    base_url: String,
-}
+    /// Exclude data with velocity at peak brightness below this value (km/s)
+    pub fn vel_min(&mut self, vel: f64) {
+        self.vel_min = Some(vel)
+    }
 
-#[derive(Debug, PartialEq)]
-pub enum QueryType {
-    /// designation (provisional or IAU-number) of the desired object (e.g., 2015 AB or 141P or 433).
-    /// NOTE: when submitting a des containing a space in your query string, you must replace the space with %20, for example 2015%20AB.
-    DES,
-    /// object search string: designation in various forms (including MPC packed form), case-insensitive name, or SPK-ID;
-    /// designation can be an alternate provisional designation; examples: atira, 2003 CP20, 2003cp20, K03C20P, 163693, 2163693
-    SSTR,
-}
+    /// Exclude data with velocity at peak brightness above this value (km/s)
+    pub fn vel_max(&mut self, vel: f64) {
+        self.vel_max = Some(vel)
+    }
 
-impl MissionDesign {
-    pub fn new() -> Self {
-        MissionDesign {
-            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api?"),
-        }
+    /// Exclude data with altitude below this value (km)
+    pub fn alt_min(&mut self, alt: f64) {
+        self.alt_min = Some(alt)
     }
 
-    /// Mission Design: Q mode (query)
-    pub fn query(&self, query_type: QueryType, query: &str) -> Result<Response, Box<dyn Error>> {
-        // Default Query Mode
-        match query_type {
-            QueryType::DES => {
-                let url = format!("{}des={}", self.base_url, query);
+    /// Exclude data with altitude above this value (km)
+    pub fn alt_max(&mut self, alt: f64) {
+        self.alt_max = Some(alt)
+    }
 
-                let res: String = ureq::get(&url).call()?.into_string()?;
-                let mission = into_response(res.as_str()).unwrap();
+    /// Only return events with a known location
+    pub fn req_loc(&mut self, req_loc: bool) {
+        self.req_loc = Some(req_loc)
+    }
 
-                Ok(mission)
-            }
-            QueryType::SSTR => {
-                let url = format!("{}sstr={}", self.base_url, query);
+    /// Only return events with a known altitude
+    pub fn req_alt(&mut self, req_alt: bool) {
+        self.req_alt = Some(req_alt)
+    }
 
-                let res: String = ureq::get(&url).call()?.into_string()?;
-                let mission = into_response(res.as_str()).unwrap();
+    /// Only return events with a known velocity
+    pub fn req_vel(&mut self, req_vel: bool) {
+        self.req_vel = Some(req_vel)
+    }
 
-                Ok(mission)
+    /// Include the velocity components vx, vy and vz. Requires [`FireballClient::req_vel`].
+    pub fn vel_comp(&mut self, vel_comp: bool) {
+        self.vel_comp = Some(vel_comp)
+    }
+
+    /// Sort results by the given field, in descending order if `descending` is true
+    pub fn sort(&mut self, field: FireballSort, descending: bool) {
+        self.sort = Some((field, descending))
+    }
+
+    /// Validates the filters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let date_min = self.date_min.as_deref().map(fireball_date).transpose()?;
+        let date_max = self.date_max.as_deref().map(fireball_date).transpose()?;
+        if let (Some(min), Some(max)) = (date_min, date_max) {
+            if min > max {
+                return Err("date-min is later than date-max".into());
             }
         }
-    }
-}
+        check_range("energy", self.energy_min, self.energy_max)?;
+        check_range("impact-e", self.impact_e_min, self.impact_e_max)?;
+        check_range("vel", self.vel_min, self.vel_max)?;
+        check_range("alt", self.alt_min, self.alt_max)?;
 
-/// Base Client for Mission Design in Accessible Mode (A)
-/// # Example
-/// ```
-/// use voyager_client::jpl::*;
-///
-/// let mut base = MissionDesignAccessible::new();
-/// base.limit(10);
-/// base.crit(1);
-/// base.year(String::from("2025,2026,2027,2028,2029"));
-///
-/// base.lim_crit_year().unwrap();
-/// ```
-#[derive(Debug, PartialEq)]
-pub struct MissionDesignAccessible {
-    base_url: String,
-    limit: Option<u32>,
-    crit: Option<u8>,
-    year: Option<String>,
-    rdvz: Option<bool>,
-    class: Option<String>,
-}
+        if self.limit == Some(0) {
+            return Err("limit must be greater than zero".into());
+        }
+        if self.vel_comp == Some(true) && self.req_vel != Some(true) {
+            return Err("vel-comp requires req-vel to be set".into());
+        }
 
-impl MissionDesignAccessible {
-    /// Create a new MissionDesignAccessible base client with None set for the limit, crit, year, rdvz and class fields
-    pub fn new() -> Self {
-        MissionDesignAccessible {
-            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api?"),
-            limit: None,
-            crit: None,
-            year: None,
-            rdvz: None,
-            class: None,
+        let number = |value: Option<f64>| value.map(|v| v.to_string());
+        let flag = |value: Option<bool>| value.map(|v| v.to_string());
+        let sort = self.sort.map(|(field, descending)| {
+            let prefix = if descending { "-" } else { "" };
+            format!("{}{}", prefix, field.as_str())
+        });
+
+        let params = [
+            ("date-min", self.date_min.clone()),
+            ("date-max", self.date_max.clone()),
+            ("energy-min", number(self.energy_min)),
+            ("energy-max", number(self.energy_max)),
+            ("impact-e-min", number(self.impact_e_min)),
+            ("impact-e-max", number(self.impact_e_max)),
+            ("vel-min", number(self.vel_min)),
+            ("vel-max", number(self.vel_max)),
+            ("alt-min", number(self.alt_min)),
+            ("alt-max", number(self.alt_max)),
+            ("req-loc", flag(self.req_loc)),
+            ("req-alt", flag(self.req_alt)),
+            ("req-vel", flag(self.req_vel)),
+            ("vel-comp", flag(self.vel_comp)),
+            ("sort", sort),
+            ("limit", self.limit.map(|limit| limit.to_string())),
+        ];
+        let mut url = UrlBuilder::new(&self.base_url);
+        for (name, value) in params.iter() {
+            if let Some(value) = value {
+                url.param(name, value);
+            }
         }
+        Ok(url.build())
     }
 
-    pub fn limit(&mut self, limit: u32) {
-        self.limit = Some(limit)
-    }
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
 
-    pub fn crit(&mut self, crit: u8) {
-        self.crit = Some(crit)
-    }
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let fireball = into_response(res.as_str())?;
 
-    pub fn year(&mut self, year: String) {
-        self.year = Some(year)
+        Ok(fireball)
     }
+}
 
-    pub fn rdvz(&mut self, rdvz: bool) {
-        self.rdvz = Some(rdvz)
+fn fireball_date(date: &str) -> Result<NaiveDateTime, Box<dyn Error>> {
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(date, "%Y-%m-%dT%H:%M:%S") {
+        return Ok(datetime);
     }
-
-    pub fn class(&mut self, class: String) {
-        self.class = Some(class)
+    match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+        Ok(day) => Ok(day.and_hms(0, 0, 0)),
+        Err(_) => Err(format!(
+            "invalid date {}, expected YYYY-MM-DD or YYYY-MM-DDThh:mm:ss",
+            date
+        )
+        .into()),
     }
+}
 
-    /// Must set Limit, Crit, and year values
-    pub fn lim_crit_year(&self) -> Result<Response, Box<dyn Error>> {
-        assert!(self.limit != None, "Limit is None");
-        assert!(self.crit != None, "Crit is None");
-        assert!(self.year != None, "Year is None");
-
-        let url = format!(
-            "{}lim={}&crit={}&year={}",
-            self.base_url,
-            self.limit.as_ref().unwrap(),
-            self.crit.as_ref().unwrap(),
-            self.year.as_ref().unwrap()
-        );
-
-        let res = ureq::get(&url).call()?.into_string()?;
-        let pretty = into_response(res.as_str()).unwrap();
+/// Rejects ranges whose minimum is greater than their maximum.
+pub(crate) fn check_range<T: PartialOrd + std::fmt::Display>(
+    name: &str,
+    min: Option<T>,
+    max: Option<T>,
+) -> Result<(), Box<dyn Error>> {
+    if let (Some(min), Some(max)) = (min, max) {
+        if min > max {
+            return Err(
+                format!("{}-min {} is greater than {}-max {}", name, min, name, max).into(),
+            );
+        }
+    }
+    Ok(())
+}
 
-        Ok(pretty)
+impl FireballClient {
+    /// Queries the endpoint and parses each row into a [`Fireball`].
+    pub fn fireballs(&self) -> Result<Vec<Fireball>, Box<dyn Error>> {
+        let res = self.query()?;
+        Fireball::from_response(&res)
     }
 }
 
-/// Base Client for Mission Design in Map Mode (M)
-/// # Example
-/// ```
-/// use voyager_client::jpl::*;
-///
-/// let mut base = MissionDesignMap::new();
-/// base.designation("2012%20TC4");
-/// base.mjd(58490);
-/// base.span(3652);
-/// base.tof(10, 36);
-/// base.step(2);
+/// A single fireball event reported by the Fireball API.
 ///
-/// base.query().unwrap();
-/// ```
-
-#[derive(Debug, PartialEq)]
-pub struct MissionDesignMap {
-    base_url: String,
-    des: Option<String>,
-    mjd0: Option<u32>,
-    span: Option<u32>,
-    tof_min: Option<u32>,
-    tof_max: Option<u32>,
-    step: Option<u8>,
+/// Latitude and longitude are signed, combining the `lat-dir`/`lon-dir` fields
+/// (north and east positive). Location, altitude and velocity are not known for every event.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fireball {
+    /// Date and time of peak brightness (UT)
+    pub date: NaiveDateTime,
+    /// Approximate total radiated energy, in units of 10^10 joules
+    pub energy: f64,
+    /// Approximate total impact energy, in kilotons
+    pub impact_e: f64,
+    /// Latitude in degrees, negative for south
+    pub lat: Option<f64>,
+    /// Longitude in degrees, negative for west
+    pub lon: Option<f64>,
+    /// Altitude above the geoid, in kilometers
+    pub alt: Option<f64>,
+    /// Velocity at peak brightness, in kilometers per second
+    pub vel: Option<f64>,
+    /// Pre-entry velocity, Earth-centered Earth-fixed X component (km/s), only present when
+    /// `vel-comp` was requested
+    pub vx: Option<f64>,
+    /// Pre-entry velocity, Earth-centered Earth-fixed Y component (km/s), only present when
+    /// `vel-comp` was requested
+    pub vy: Option<f64>,
+    /// Pre-entry velocity, Earth-centered Earth-fixed Z component (km/s), only present when
+    /// `vel-comp` was requested
+    pub vz: Option<f64>,
 }
 
-impl MissionDesignMap {
-    pub fn new() -> Self {
-        MissionDesignMap {
-            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api?"),
-            des: None,
-            mjd0: None,
-            span: None,
-            tof_min: None,
-            tof_max: None,
-            step: None,
-        }
+impl Fireball {
+    /// Parses every row of a Fireball API response.
+    pub fn from_response(res: &Response) -> Result<Vec<Fireball>, Box<dyn Error>> {
+        let json = res.json()?;
+        records(&json)?.iter().map(Fireball::from_record).collect()
     }
 
-    pub fn designation(&mut self, des: &str) {
-        self.des = Some(String::from(des))
+    fn from_record(record: &JsonValue) -> Result<Fireball, Box<dyn Error>> {
+        let date = field_str(record, "date").ok_or("fireball is missing its date")?;
+        let date = NaiveDateTime::parse_from_str(&date, "%Y-%m-%d %H:%M:%S")?;
+
+        let lat = signed(field_f64(record, "lat"), field_str(record, "lat-dir"), "S");
+        let lon = signed(field_f64(record, "lon"), field_str(record, "lon-dir"), "W");
+
+        Ok(Fireball {
+            date,
+            energy: field_f64(record, "energy").ok_or("fireball is missing its energy")?,
+            impact_e: field_f64(record, "impact-e")
+                .ok_or("fireball is missing its impact energy")?,
+            lat,
+            lon,
+            alt: field_f64(record, "alt"),
+            vel: field_f64(record, "vel"),
+            vx: field_f64(record, "vx"),
+            vy: field_f64(record, "vy"),
+            vz: field_f64(record, "vz"),
+        })
     }
 
-    pub fn mjd(&mut self, x: u32) {
-        self.mjd0 = Some(x)
+    /// True when both latitude and longitude are known.
+    pub fn is_located(&self) -> bool {
+        self.lat.is_some() && self.lon.is_some()
     }
+}
 
-    pub fn span(&mut self, span: u32) {
-        self.span = Some(span)
+fn signed(value: Option<f64>, dir: Option<String>, negative: &str) -> Option<f64> {
+    let value = value?;
+    match dir {
+        Some(dir) if dir.eq_ignore_ascii_case(negative) => Some(-value),
+        _ => Some(value),
     }
+}
 
-    pub fn tof(&mut self, min: u32, max: u32) {
-        self.tof_min = Some(min);
-        self.tof_max = Some(max);
+/// Sums the impact energy (kilotons) of the given fireballs per calendar year.
+pub fn fireball_energy_by_year(fireballs: &[Fireball]) -> BTreeMap<i32, f64> {
+    let mut years = BTreeMap::new();
+    for fireball in fireballs {
+        *years.entry(fireball.date.year()).or_insert(0.0) += fireball.impact_e;
     }
+    years
+}
 
-    pub fn step(&mut self, step: u8) {
-        self.step = Some(step)
-    }
+/// Returns the `n` fireballs with the highest impact energy, strongest first.
+pub fn strongest_fireballs(fireballs: &[Fireball], n: usize) -> Vec<&Fireball> {
+    let mut sorted: Vec<&Fireball> = fireballs.iter().collect();
+    sorted.sort_by(|a, b| b.impact_e.total_cmp(&a.impact_e));
+    sorted.truncate(n);
+    sorted
+}
 
-    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
-        assert!(self.des != None, "Des is None");
-        assert!(self.mjd0 != None, "Mjd0 is None");
-        assert!(self.span != None, "Span is None");
-        assert!(self.tof_min != None, "tof_min is None");
-        assert!(self.tof_max != None, "tof_max is None");
-        assert!(self.step != None, "Step is None");
-
-        let url = format!(
-            "{}des={}&mjd0={}&span={}&tof-min={}&tof-max={}&step={}",
-            self.base_url,
-            self.des.as_ref().unwrap(),
-            self.mjd0.as_ref().unwrap(),
-            self.span.as_ref().unwrap(),
-            self.tof_min.as_ref().unwrap(),
-            self.tof_max.as_ref().unwrap(),
-            self.step.as_ref().unwrap()
-        );
-
-        println!("Url: {}", url);
+/// Builds a GeoJSON FeatureCollection of point features for every located fireball.
+/// Events without a known location are skipped.
+pub fn fireballs_to_geojson(fireballs: &[Fireball]) -> JsonValue {
+    let features: Vec<JsonValue> = fireballs
+        .iter()
+        .filter_map(|fireball| {
+            let (lat, lon) = (fireball.lat?, fireball.lon?);
+            Some(json!({
+                "type": "Feature",
+                "geometry": {
+                    "type": "Point",
+                    "coordinates": [lon, lat],
+                },
+                "properties": {
+                    "date": fireball.date.format("%Y-%m-%d %H:%M:%S").to_string(),
+                    "energy": fireball.energy,
+                    "impact-e": fireball.impact_e,
+                    "alt": fireball.alt,
+                    "vel": fireball.vel,
+                },
+            }))
+        })
+        .collect();
+
+    json!({
+        "type": "FeatureCollection",
+        "features": features,
+    })
+}
 
-        let res: String = ureq::get(&url).call()?.into_string()?;
-        let map = into_response(res.as_str()).unwrap();
+#[derive(Debug, PartialEq)]
+pub enum QueryType {
+    /// designation (provisional or IAU-number) of the desired object (e.g., 2015 AB or 141P or 433).
+    DES,
+    /// object search string: designation in various forms (including MPC packed form), case-insensitive name, or SPK-ID;
+    /// designation can be an alternate provisional designation; examples: atira, 2003 CP20, 2003cp20, K03C20P, 163693, 2163693
+    SSTR,
+    /// SPK-ID of the desired object (e.g., 2000433)
+    SPK,
+}
 
-        Ok(map)
+impl QueryType {
+    /// Name of the query parameter for this query type
+    pub(crate) fn as_param(&self) -> &'static str {
+        match self {
+            QueryType::DES => "des",
+            QueryType::SSTR => "sstr",
+            QueryType::SPK => "spk",
+        }
     }
-}
\ No newline at end of file
+}
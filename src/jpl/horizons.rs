@@ -0,0 +1,486 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use super::{check_error, field_str, OrbitElements};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Horizons ephemeris API.
+///
+/// Ephemerides are always requested in CSV form, so the `$$SOE`/`$$EOE` block of the result can
+/// be parsed into a [`HorizonsTable`] instead of being left as a text blob.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// // Heliocentric state vectors of Mars
+/// let mut base = HorizonsClient::new();
+/// base.command("499");
+/// base.center("500@10");
+/// base.ephem_type(EphemType::Vectors);
+/// base.units(HorizonsUnits::AuD);
+/// base.start_time("2022-01-01");
+/// base.stop_time("2022-02-01");
+/// base.step_size("1 d");
+///
+/// let table = base.table().unwrap();
+/// let vectors = table.vectors().unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct HorizonsClient {
+    base_url: String,
+    command: Option<String>,
+    center: Option<String>,
+    ephem_type: Option<EphemType>,
+    start_time: Option<String>,
+    stop_time: Option<String>,
+    step_size: Option<String>,
+    quantities: Option<String>,
+    units: Option<HorizonsUnits>,
+}
+
+/// Type of ephemeris generated by Horizons.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EphemType {
+    /// Observer table: positions, magnitudes and other quantities as seen from the center
+    Observer,
+    /// Cartesian state vectors relative to the center
+    Vectors,
+    /// Osculating orbital elements relative to the center
+    Elements,
+}
+
+/// Output units of vectors and elements tables.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HorizonsUnits {
+    /// Kilometers and seconds (the Horizons default)
+    KmS,
+    /// Kilometers and days
+    KmD,
+    /// Astronomical units and days, as used by [`crate::orbit`]
+    AuD,
+}
+
+impl HorizonsClient {
+    pub fn new() -> Self {
+        HorizonsClient {
+            base_url: String::from("https://ssd.jpl.nasa.gov/api/horizons.api"),
+            command: None,
+            center: None,
+            ephem_type: None,
+            start_time: None,
+            stop_time: None,
+            step_size: None,
+            quantities: None,
+            units: None,
+        }
+    }
+
+    /// Target body, e.g. 499 for Mars or "DES=2012 TC4;" for a small body
+    pub fn command(&mut self, command: &str) {
+        self.command = Some(String::from(command))
+    }
+
+    /// Coordinate center, e.g. 500@399 (geocenter) or 500@10 (Sun body center)
+    pub fn center(&mut self, center: &str) {
+        self.center = Some(String::from(center))
+    }
+
+    pub fn ephem_type(&mut self, ephem_type: EphemType) {
+        self.ephem_type = Some(ephem_type)
+    }
+
+    /// Start of the ephemeris, e.g. 2022-01-01 or 2022-01-01 12:00
+    pub fn start_time(&mut self, start_time: &str) {
+        self.start_time = Some(String::from(start_time))
+    }
+
+    /// End of the ephemeris
+    pub fn stop_time(&mut self, stop_time: &str) {
+        self.stop_time = Some(String::from(stop_time))
+    }
+
+    /// Step between ephemeris points, e.g. "1 d", "6 h" or "10 m"
+    pub fn step_size(&mut self, step_size: &str) {
+        self.step_size = Some(String::from(step_size))
+    }
+
+    /// Comma separated list of observer table quantity codes, e.g. "1,9,20,23"
+    pub fn quantities(&mut self, quantities: &str) {
+        self.quantities = Some(String::from(quantities))
+    }
+
+    /// Output units of vectors and elements tables
+    pub fn units(&mut self, units: HorizonsUnits) {
+        self.units = Some(units)
+    }
+
+    /// Validates the parameters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let command = self.command.as_ref().ok_or("Horizons requires a COMMAND")?;
+        for (name, value) in [
+            ("START_TIME", &self.start_time),
+            ("STOP_TIME", &self.stop_time),
+            ("STEP_SIZE", &self.step_size),
+        ] {
+            if value.is_none() {
+                return Err(format!("Horizons requires a {}", name).into());
+            }
+        }
+
+        let ephem_type = self.ephem_type.unwrap_or(EphemType::Observer);
+        if self.quantities.is_some() && ephem_type != EphemType::Observer {
+            return Err("QUANTITIES only apply to OBSERVER ephemerides".into());
+        }
+        if self.units.is_some() && ephem_type == EphemType::Observer {
+            return Err("OUT_UNITS only apply to VECTORS and ELEMENTS ephemerides".into());
+        }
+
+        let ephem_type = match ephem_type {
+            EphemType::Observer => "OBSERVER",
+            EphemType::Vectors => "VECTORS",
+            EphemType::Elements => "ELEMENTS",
+        };
+        let units = self.units.map(|units| match units {
+            HorizonsUnits::KmS => String::from("KM-S"),
+            HorizonsUnits::KmD => String::from("KM-D"),
+            HorizonsUnits::AuD => String::from("AU-D"),
+        });
+
+        let params = [
+            ("COMMAND", Some(command.clone())),
+            ("OBJ_DATA", Some(String::from("NO"))),
+            ("MAKE_EPHEM", Some(String::from("YES"))),
+            ("EPHEM_TYPE", Some(String::from(ephem_type))),
+            ("CENTER", self.center.clone()),
+            ("START_TIME", self.start_time.clone()),
+            ("STOP_TIME", self.stop_time.clone()),
+            ("STEP_SIZE", self.step_size.clone()),
+            ("QUANTITIES", self.quantities.clone()),
+            ("OUT_UNITS", units),
+            ("CSV_FORMAT", Some(String::from("YES"))),
+        ];
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("format", "json");
+        for (name, value) in params.iter() {
+            if let Some(value) = value {
+                url.param(name, format!("'{}'", value));
+            }
+        }
+        Ok(url.build())
+    }
+
+    /// Horizons: generate the ephemeris
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let horizons = into_response(res.as_str())?;
+        check_error(&horizons.json()?)?;
+
+        Ok(horizons)
+    }
+
+    /// Generates the ephemeris and parses it into a [`HorizonsTable`]
+    pub fn table(&self) -> Result<HorizonsTable, Box<dyn Error>> {
+        HorizonsTable::from_response(&self.query()?)
+    }
+}
+
+impl Default for HorizonsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The rows between `$$SOE` and `$$EOE` of a CSV formatted Horizons result, with the column
+/// names from the header line that precedes them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HorizonsTable {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl HorizonsTable {
+    /// Parses the `result` text of a Horizons response.
+    pub fn from_response(res: &Response) -> Result<HorizonsTable, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        let result = field_str(&json, "result").ok_or("Horizons response has no result")?;
+        HorizonsTable::parse(&result)
+    }
+
+    /// Parses the text of a CSV formatted Horizons ephemeris.
+    pub fn parse(result: &str) -> Result<HorizonsTable, Box<dyn Error>> {
+        let lines: Vec<&str> = result.lines().collect();
+        let start = lines
+            .iter()
+            .position(|line| line.trim() == "$$SOE")
+            .ok_or("Horizons result has no $$SOE marker")?;
+        let end = lines[start..]
+            .iter()
+            .position(|line| line.trim() == "$$EOE")
+            .map(|end| start + end)
+            .ok_or("Horizons result has no $$EOE marker")?;
+
+        let header = lines[..start]
+            .iter()
+            .rev()
+            .find(|line| !line.trim().is_empty() && !line.trim_start().starts_with('*'))
+            .ok_or("Horizons result has no column header")?;
+
+        Ok(HorizonsTable {
+            columns: split_csv(header),
+            rows: lines[start + 1..end]
+                .iter()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| split_csv(line))
+                .collect(),
+        })
+    }
+
+    /// Index of the first column with the given name
+    pub fn column(&self, name: &str) -> Option<usize> {
+        self.columns.iter().position(|column| column == name)
+    }
+
+    /// Value of a column in a row
+    pub fn get(&self, row: usize, name: &str) -> Option<&str> {
+        let column = self.column(name)?;
+        self.rows.get(row)?.get(column).map(String::as_str)
+    }
+
+    fn number(&self, row: usize, name: &str) -> Result<f64, Box<dyn Error>> {
+        let value = self
+            .get(row, name)
+            .ok_or(format!("Horizons table has no {} column", name))?;
+        value
+            .parse()
+            .map_err(|_| format!("invalid {} value {}", name, value).into())
+    }
+
+    /// Parses a VECTORS table. Units are those requested with [`HorizonsClient::units`].
+    pub fn vectors(&self) -> Result<Vec<VectorRow>, Box<dyn Error>> {
+        (0..self.rows.len())
+            .map(|row| {
+                Ok(VectorRow {
+                    jd: self.number(row, "JDTDB")?,
+                    calendar: self.get(row, "Calendar Date (TDB)").map(String::from),
+                    position: [
+                        self.number(row, "X")?,
+                        self.number(row, "Y")?,
+                        self.number(row, "Z")?,
+                    ],
+                    velocity: [
+                        self.number(row, "VX")?,
+                        self.number(row, "VY")?,
+                        self.number(row, "VZ")?,
+                    ],
+                    lt: self.number(row, "LT").ok(),
+                    rg: self.number(row, "RG").ok(),
+                    rr: self.number(row, "RR").ok(),
+                })
+            })
+            .collect()
+    }
+
+    /// Parses an ELEMENTS table. Units are those requested with [`HorizonsClient::units`].
+    pub fn elements(&self) -> Result<Vec<ElementsRow>, Box<dyn Error>> {
+        (0..self.rows.len())
+            .map(|row| {
+                Ok(ElementsRow {
+                    jd: self.number(row, "JDTDB")?,
+                    calendar: self.get(row, "Calendar Date (TDB)").map(String::from),
+                    ec: self.number(row, "EC")?,
+                    qr: self.number(row, "QR")?,
+                    inc: self.number(row, "IN")?,
+                    om: self.number(row, "OM")?,
+                    w: self.number(row, "W")?,
+                    tp: self.number(row, "Tp")?,
+                    n: self.number(row, "N").ok(),
+                    ma: self.number(row, "MA").ok(),
+                    ta: self.number(row, "TA").ok(),
+                    a: self.number(row, "A").ok(),
+                    ad: self.number(row, "AD").ok(),
+                    pr: self.number(row, "PR").ok(),
+                })
+            })
+            .collect()
+    }
+
+    /// Parses an OBSERVER table. Right ascension and declination are read from either
+    /// sexagesimal (`ANG_FORMAT=HMS`) or decimal degree columns; every column without a
+    /// field of its own is kept in [`ObserverRow::other`].
+    pub fn observer(&self) -> Result<Vec<ObserverRow>, Box<dyn Error>> {
+        let date = self
+            .columns
+            .iter()
+            .position(|column| column.starts_with("Date"))
+            .ok_or("Horizons table has no date column")?;
+        let ra = self.columns.iter().position(|c| c.starts_with("R.A."));
+        let dec = self.columns.iter().position(|c| c.starts_with("DEC"));
+        let delta = self.column("delta");
+        let deldot = self.column("deldot");
+        // the solar and lunar presence markers follow the date, under empty headers
+        let mut markers = (date + 1..self.columns.len()).filter(|&i| self.columns[i].is_empty());
+        let solar = markers.next();
+        let lunar = markers.next();
+        let typed = [Some(date), ra, dec, delta, deldot, solar, lunar];
+
+        self.rows
+            .iter()
+            .map(|row| {
+                let cell = |column: Option<usize>| {
+                    column
+                        .and_then(|i| row.get(i))
+                        .map(String::as_str)
+                        .filter(|value| !value.is_empty())
+                };
+                let number = |column: Option<usize>| cell(column).and_then(|v| v.parse().ok());
+                let angle = |column: Option<usize>, hours: bool| -> Result<_, Box<dyn Error>> {
+                    match cell(column) {
+                        Some("n.a.") | None => Ok(None),
+                        Some(value) => Ok(Some(parse_angle(value, hours)?)),
+                    }
+                };
+
+                Ok(ObserverRow {
+                    datetime: cell(Some(date)).ok_or("row has no date")?.to_string(),
+                    solar_presence: cell(solar).map(String::from),
+                    lunar_presence: cell(lunar).map(String::from),
+                    ra: angle(ra, true)?,
+                    dec: angle(dec, false)?,
+                    delta: number(delta),
+                    deldot: number(deldot),
+                    other: self
+                        .columns
+                        .iter()
+                        .enumerate()
+                        .filter(|&(i, name)| !typed.contains(&Some(i)) && !name.is_empty())
+                        .filter_map(|(i, name)| Some((name.clone(), row.get(i)?.clone())))
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// Reads an angle in degrees, either decimal or sexagesimal such as `16 06 22.91` (hours if
+/// `hours` is set) or `-00 47 49.5`.
+fn parse_angle(value: &str, hours: bool) -> Result<f64, Box<dyn Error>> {
+    let invalid = || format!("invalid angle {}", value);
+    let parts: Vec<&str> = value.split_whitespace().collect();
+    let degrees = match parts.as_slice() {
+        [decimal] => return decimal.parse().map_err(|_| invalid().into()),
+        [whole, minutes, seconds] => {
+            let whole: f64 = whole.parse().map_err(|_| invalid())?;
+            let minutes: f64 = minutes.parse().map_err(|_| invalid())?;
+            let seconds: f64 = seconds.parse().map_err(|_| invalid())?;
+            let sign = if value.trim_start().starts_with('-') {
+                -1.0
+            } else {
+                1.0
+            };
+            sign * (whole.abs() + minutes / 60.0 + seconds / 3600.0)
+        }
+        _ => return Err(invalid().into()),
+    };
+    Ok(if hours { degrees * 15.0 } else { degrees })
+}
+
+fn split_csv(line: &str) -> Vec<String> {
+    let line = line.trim_end();
+    let line = line.strip_suffix(',').unwrap_or(line);
+    line.split(',')
+        .map(|cell| cell.trim().to_string())
+        .collect()
+}
+
+/// One row of a VECTORS table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorRow {
+    /// Julian Date (TDB)
+    pub jd: f64,
+    /// Calendar date (TDB)
+    pub calendar: Option<String>,
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+    /// One-way light time
+    pub lt: Option<f64>,
+    /// Range from the center
+    pub rg: Option<f64>,
+    /// Range rate
+    pub rr: Option<f64>,
+}
+
+/// One row of an ELEMENTS table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementsRow {
+    /// Julian Date (TDB)
+    pub jd: f64,
+    /// Calendar date (TDB)
+    pub calendar: Option<String>,
+    /// Eccentricity
+    pub ec: f64,
+    /// Periapsis distance
+    pub qr: f64,
+    /// Inclination (deg)
+    pub inc: f64,
+    /// Longitude of the ascending node (deg)
+    pub om: f64,
+    /// Argument of periapsis (deg)
+    pub w: f64,
+    /// Time of periapsis, Julian Date (TDB)
+    pub tp: f64,
+    /// Mean motion
+    pub n: Option<f64>,
+    /// Mean anomaly (deg)
+    pub ma: Option<f64>,
+    /// True anomaly (deg)
+    pub ta: Option<f64>,
+    /// Semi-major axis
+    pub a: Option<f64>,
+    /// Apoapsis distance
+    pub ad: Option<f64>,
+    /// Sidereal orbit period
+    pub pr: Option<f64>,
+}
+
+impl ElementsRow {
+    /// Converts the row into [`OrbitElements`]. Only meaningful for heliocentric elements
+    /// requested in [`HorizonsUnits::AuD`].
+    pub fn elements(&self) -> OrbitElements {
+        OrbitElements {
+            epoch: self.jd,
+            e: self.ec,
+            a: self.a,
+            q: self.qr,
+            i: self.inc,
+            om: self.om,
+            w: self.w,
+            ma: self.ma,
+            tp: Some(self.tp),
+        }
+    }
+}
+
+/// One row of an OBSERVER table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObserverRow {
+    /// Date and time (UT) as printed by Horizons, e.g. 2022-Jan-01 00:00
+    pub datetime: String,
+    /// Solar presence marker: `*` daylight, `C`, `N` or `A` twilight, `None` at night
+    pub solar_presence: Option<String>,
+    /// Lunar presence marker: `m` when the moon is up, `r`, `t` or `s` at rise, transit or set
+    pub lunar_presence: Option<String>,
+    /// Right ascension (deg)
+    pub ra: Option<f64>,
+    /// Declination (deg)
+    pub dec: Option<f64>,
+    /// Range from the observer (au)
+    pub delta: Option<f64>,
+    /// Range rate (km/s)
+    pub deldot: Option<f64>,
+    /// The remaining columns, keyed by their header
+    pub other: BTreeMap<String, String>,
+}
@@ -0,0 +1,481 @@
+use std::error::Error;
+
+use chrono::NaiveDate;
+use serde_json::{json, Value as JsonValue};
+
+use super::{check_error, field_f64, field_str, records, QueryType};
+use crate::response::*;
+use crate::time;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Mission Design API in Query Mode.
+/// # Example
+/// ```
+/// use voyager_client::jpl::*;
+///
+/// let mut base = MissionDesign::new();
+///
+/// base.query(QueryType::DES, "2012 TC4").unwrap();
+/// ```
+#[derive(Debug)]
+pub struct MissionDesign {
+    base_url: String,
+}
+
+impl MissionDesign {
+    pub fn new() -> Self {
+        MissionDesign {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api"),
+        }
+    }
+
+    /// Query url for a des, sstr or spk lookup
+    pub fn url(&self, query_type: QueryType, query: &str) -> String {
+        UrlBuilder::new(&self.base_url)
+            .param(query_type.as_param(), query)
+            .build()
+    }
+
+    /// Mission Design: Q mode (query)
+    pub fn query(&self, query_type: QueryType, query: &str) -> Result<Response, Box<dyn Error>> {
+        // Default Query Mode
+        let url = self.url(query_type, query);
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let mission = into_response(res.as_str())?;
+        check_error(&mission.json()?)?;
+
+        Ok(mission)
+    }
+
+    /// Queries an object and parses its precomputed trajectories into a [`MissionDesignResult`]
+    pub fn missions(
+        &self,
+        query_type: QueryType,
+        query: &str,
+    ) -> Result<MissionDesignResult, Box<dyn Error>> {
+        MissionDesignResult::from_response(&self.query(query_type, query)?)
+    }
+}
+
+impl Default for MissionDesign {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Base Client for Mission Design in Accessible Mode (A)
+///
+/// The limit, ranking criterion and launch years are required, the rendezvous flag and the
+/// orbit class are optional.
+/// # Example
+/// ```
+/// use voyager_client::jpl::*;
+///
+/// let mut base = MissionDesignAccessible::new();
+/// base.limit(10);
+/// base.crit(1);
+/// base.year(String::from("2025,2026,2027,2028,2029"));
+///
+/// base.lim_crit_year().unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct MissionDesignAccessible {
+    base_url: String,
+    limit: Option<u32>,
+    crit: Option<u8>,
+    year: Option<String>,
+    rdvz: Option<bool>,
+    class: Option<String>,
+}
+
+impl MissionDesignAccessible {
+    /// Create a new MissionDesignAccessible base client with None set for the limit, crit, year, rdvz and class fields
+    pub fn new() -> Self {
+        MissionDesignAccessible {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api"),
+            limit: None,
+            crit: None,
+            year: None,
+            rdvz: None,
+            class: None,
+        }
+    }
+
+    /// Maximum number of objects returned
+    pub fn limit(&mut self, limit: u32) {
+        self.limit = Some(limit)
+    }
+
+    /// Criterion the objects are ranked by, 1 to 6 as listed in the mdesign documentation
+    pub fn crit(&mut self, crit: u8) {
+        self.crit = Some(crit)
+    }
+
+    /// Comma separated launch years, e.g. 2025,2026
+    pub fn year(&mut self, year: String) {
+        self.year = Some(year)
+    }
+
+    /// Rank rendezvous trajectories (true) or flybys (false)
+    pub fn rdvz(&mut self, rdvz: bool) {
+        self.rdvz = Some(rdvz)
+    }
+
+    /// Restrict the results to an orbit class, e.g. APO, ATE or AMO
+    pub fn class(&mut self, class: String) {
+        self.class = Some(class)
+    }
+
+    /// Validates the parameters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let limit = self.limit.ok_or("accessible mode requires a limit")?;
+        if limit == 0 {
+            return Err("limit must be at least 1".into());
+        }
+        let crit = self.crit.ok_or("accessible mode requires a crit")?;
+        if !(1..=6).contains(&crit) {
+            return Err(format!("{} is not a valid crit, expected 1 to 6", crit).into());
+        }
+        let year = self
+            .year
+            .as_ref()
+            .ok_or("accessible mode requires a year")?;
+        for y in year.split(',') {
+            if y.len() != 4 || !y.chars().all(|c| c.is_ascii_digit()) {
+                return Err(format!("{} is not a valid launch year", y).into());
+            }
+        }
+
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("lim", limit)
+            .param("crit", crit)
+            .param("year", year);
+        if let Some(rdvz) = self.rdvz {
+            url.param("rdvz", rdvz);
+        }
+        if let Some(class) = &self.class {
+            if class.len() != 3 || !class.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(format!("{} is not a valid orbit class", class).into());
+            }
+            url.param("class", class.to_uppercase());
+        }
+
+        Ok(url.build())
+    }
+
+    /// Mission Design: A mode (accessible)
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
+
+        let res = ureq::get(&url).call()?.into_string()?;
+        let accessible = into_response(res.as_str())?;
+        check_error(&accessible.json()?)?;
+
+        Ok(accessible)
+    }
+
+    /// Must set Limit, Crit, and year values
+    pub fn lim_crit_year(&self) -> Result<Response, Box<dyn Error>> {
+        self.query()
+    }
+
+    /// Queries the accessible objects and parses them into [`AccessibleTarget`] rows
+    pub fn targets(&self) -> Result<Vec<AccessibleTarget>, Box<dyn Error>> {
+        AccessibleTarget::from_response(&self.query()?)
+    }
+}
+
+impl Default for MissionDesignAccessible {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Base Client for Mission Design in Map Mode (M)
+/// # Example
+/// ```
+/// use voyager_client::jpl::*;
+///
+/// let mut base = MissionDesignMap::new();
+/// base.designation("2012 TC4");
+/// base.mjd(58490);
+/// base.span(3652);
+/// base.tof(10, 36);
+/// base.step(2);
+///
+/// base.query().unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct MissionDesignMap {
+    base_url: String,
+    des: Option<String>,
+    mjd0: Option<u32>,
+    span: Option<u32>,
+    tof_min: Option<u32>,
+    tof_max: Option<u32>,
+    step: Option<u8>,
+}
+
+impl MissionDesignMap {
+    pub fn new() -> Self {
+        MissionDesignMap {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/mdesign.api"),
+            des: None,
+            mjd0: None,
+            span: None,
+            tof_min: None,
+            tof_max: None,
+            step: None,
+        }
+    }
+
+    pub fn designation(&mut self, des: &str) {
+        self.des = Some(String::from(des))
+    }
+
+    pub fn mjd(&mut self, x: u32) {
+        self.mjd0 = Some(x)
+    }
+
+    /// Sets mjd0 from the first departure date, which can't be before the MJD epoch
+    /// (1858-11-17)
+    pub fn start_date(&mut self, date: NaiveDate) -> Result<(), Box<dyn Error>> {
+        let mjd = u32::try_from(time::mjd_of_date(date))
+            .map_err(|_| format!("{} is outside the MJD range", date))?;
+        self.mjd0 = Some(mjd);
+        Ok(())
+    }
+
+    pub fn span(&mut self, span: u32) {
+        self.span = Some(span)
+    }
+
+    pub fn tof(&mut self, min: u32, max: u32) {
+        self.tof_min = Some(min);
+        self.tof_max = Some(max);
+    }
+
+    pub fn step(&mut self, step: u8) {
+        self.step = Some(step)
+    }
+
+    /// Validates the parameters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let des = self.des.as_ref().ok_or("map mode requires a des")?;
+        let mjd0 = self.mjd0.ok_or("map mode requires an mjd0")?;
+        let span = self.span.ok_or("map mode requires a span")?;
+        let (tof_min, tof_max) = match (self.tof_min, self.tof_max) {
+            (Some(min), Some(max)) => (min, max),
+            _ => return Err("map mode requires a time of flight range".into()),
+        };
+        if tof_min > tof_max {
+            return Err(format!("tof-min {} is greater than tof-max {}", tof_min, tof_max).into());
+        }
+        let step = self.step.ok_or("map mode requires a step")?;
+        if step == 0 {
+            return Err("step must be at least 1".into());
+        }
+
+        Ok(UrlBuilder::new(&self.base_url)
+            .param("des", des)
+            .param("mjd0", mjd0)
+            .param("span", span)
+            .param("tof-min", tof_min)
+            .param("tof-max", tof_max)
+            .param("step", step)
+            .build())
+    }
+
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let map = into_response(res.as_str())?;
+        check_error(&map.json()?)?;
+
+        Ok(map)
+    }
+}
+
+impl Default for MissionDesignMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The object a Q mode query resolved to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissionObject {
+    /// Primary designation
+    pub des: String,
+    /// Full name of the object
+    pub fullname: Option<String>,
+    /// SPK-ID
+    pub spkid: Option<String>,
+    /// Orbit class, e.g. Apollo
+    pub orbit_class: Option<String>,
+    /// Orbit condition code
+    pub condition_code: Option<u8>,
+    /// Data arc span (days)
+    pub data_arc: Option<f64>,
+}
+
+impl MissionObject {
+    pub(crate) fn from_json(json: &JsonValue) -> Result<MissionObject, Box<dyn Error>> {
+        // the orbit class is either a plain string or a {name, code} object
+        let orbit_class = match json.get("orbit_class") {
+            Some(class @ JsonValue::Object(_)) => field_str(class, "name"),
+            _ => field_str(json, "orbit_class"),
+        };
+
+        Ok(MissionObject {
+            des: field_str(json, "des").ok_or("mission design object has no des")?,
+            fullname: field_str(json, "fullname"),
+            spkid: field_str(json, "spkid"),
+            orbit_class,
+            condition_code: field_f64(json, "condition_code").map(|code| code as u8),
+            data_arc: field_f64(json, "data_arc"),
+        })
+    }
+}
+
+/// A ballistic Earth-to-object trajectory from the mdesign trajectory tables.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mission {
+    /// Departure date
+    pub date0: Option<String>,
+    /// Departure date (MJD)
+    pub mjd0: f64,
+    /// Arrival date
+    pub datef: Option<String>,
+    /// Arrival date (MJD)
+    pub mjdf: f64,
+    /// Time of flight (days)
+    pub tof: Option<f64>,
+    /// Departure C3 energy (km^2/s^2)
+    pub c3_dep: Option<f64>,
+    /// Departure hyperbolic excess velocity (km/s)
+    pub vinf_dep: Option<f64>,
+    /// Arrival hyperbolic excess velocity (km/s)
+    pub vinf_arr: Option<f64>,
+    /// Departure delta-v (km/s)
+    pub dv_dep: Option<f64>,
+    /// Arrival delta-v (km/s)
+    pub dv_arr: Option<f64>,
+    /// Total delta-v (km/s)
+    pub dv_tot: Option<f64>,
+    /// Earth distance at arrival (au)
+    pub earth_dist: Option<f64>,
+    /// Sun-object-Earth phase angle at arrival (deg)
+    pub phase_ang: Option<f64>,
+}
+
+impl Mission {
+    fn from_record(record: &JsonValue) -> Result<Mission, Box<dyn Error>> {
+        let mjd0 = field_f64(record, "MJD0").or_else(|| field_f64(record, "mjd0"));
+        let mjdf = field_f64(record, "MJDF").or_else(|| field_f64(record, "mjdf"));
+
+        Ok(Mission {
+            date0: field_str(record, "date0"),
+            mjd0: mjd0.ok_or("trajectory has no departure MJD")?,
+            datef: field_str(record, "datef"),
+            mjdf: mjdf.ok_or("trajectory has no arrival MJD")?,
+            tof: field_f64(record, "tof"),
+            c3_dep: field_f64(record, "c3_dep"),
+            vinf_dep: field_f64(record, "vinf_dep"),
+            vinf_arr: field_f64(record, "vinf_arr"),
+            dv_dep: field_f64(record, "dv_dep"),
+            dv_arr: field_f64(record, "dv_arr"),
+            dv_tot: field_f64(record, "dv_tot"),
+            earth_dist: field_f64(record, "earth_dist"),
+            phase_ang: field_f64(record, "phase_ang"),
+        })
+    }
+}
+
+/// The result of a Q mode query: the object and its selected trajectories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MissionDesignResult {
+    pub object: MissionObject,
+    pub missions: Vec<Mission>,
+}
+
+impl MissionDesignResult {
+    /// Parses a Q mode response.
+    pub fn from_response(res: &Response) -> Result<MissionDesignResult, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        let object = json.get("object").ok_or("response has no object")?;
+
+        let table = json!({
+            "fields": json.get("fields"),
+            "data": json.get("selectedMissions"),
+        });
+        let missions = records(&table)?
+            .iter()
+            .map(Mission::from_record)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(MissionDesignResult {
+            object: MissionObject::from_json(object)?,
+            missions,
+        })
+    }
+
+    /// The trajectory with the lowest total delta-v, if any reports one
+    pub fn lowest_delta_v(&self) -> Option<&Mission> {
+        self.missions
+            .iter()
+            .filter(|mission| mission.dv_tot.is_some())
+            .min_by(|a, b| {
+                a.dv_tot
+                    .unwrap_or_default()
+                    .total_cmp(&b.dv_tot.unwrap_or_default())
+            })
+    }
+}
+
+/// An object from the A mode ranking, with its best trajectory.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccessibleTarget {
+    /// Object name or designation
+    pub name: String,
+    /// Full name of the object
+    pub fullname: Option<String>,
+    /// Orbit class code, e.g. APO
+    pub class: Option<String>,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Orbit condition code
+    pub condition_code: Option<u8>,
+    /// The trajectory the object was ranked by
+    pub mission: Mission,
+}
+
+impl AccessibleTarget {
+    /// Parses every row of an A mode response.
+    pub fn from_response(res: &Response) -> Result<Vec<AccessibleTarget>, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        records(&json)?
+            .iter()
+            .map(AccessibleTarget::from_record)
+            .collect()
+    }
+
+    fn from_record(record: &JsonValue) -> Result<AccessibleTarget, Box<dyn Error>> {
+        let name = field_str(record, "name")
+            .or_else(|| field_str(record, "des"))
+            .ok_or("accessible target has no name")?;
+
+        Ok(AccessibleTarget {
+            name,
+            fullname: field_str(record, "full_name").or_else(|| field_str(record, "fullname")),
+            class: field_str(record, "class"),
+            h: field_f64(record, "H"),
+            condition_code: field_f64(record, "condition_code").map(|code| code as u8),
+            mission: Mission::from_record(record)?,
+        })
+    }
+}
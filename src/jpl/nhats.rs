@@ -0,0 +1,350 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, field_f64, field_str, records, AccessibleTarget, QueryType};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+const DV: [u8; 9] = [4, 5, 6, 7, 8, 9, 10, 11, 12];
+const DUR: [u16; 14] = [
+    60, 90, 120, 150, 180, 210, 240, 270, 300, 330, 360, 390, 420, 450,
+];
+const STAY: [u8; 4] = [8, 16, 24, 32];
+const LAUNCH: [&str; 6] = [
+    "2015-2020",
+    "2020-2025",
+    "2025-2030",
+    "2030-2035",
+    "2035-2040",
+    "2015-2040",
+];
+
+/// Base Client for the JPL NHATS API (Near-Earth Object Human Space Flight Accessible Targets Study).
+///
+/// The constraints only accept the values NHATS precomputes trajectories for, and are checked
+/// when the query url is built. Results can be paired with those of
+/// [`MissionDesignAccessible`](super::MissionDesignAccessible) with [`join_accessible`].
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = NhatsClient::new();
+/// base.dv(6);
+/// base.dur(360);
+/// base.launch("2025-2030");
+///
+/// let targets = base.objects().unwrap();
+/// let cheapest = lowest_delta_v(&targets, 5);
+///
+/// let detail = base.detail(QueryType::DES, &cheapest[0].des).unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct NhatsClient {
+    base_url: String,
+    dv: Option<u8>,
+    dur: Option<u16>,
+    stay: Option<u8>,
+    launch: Option<String>,
+    h: Option<u8>,
+    occ: Option<u8>,
+}
+
+impl NhatsClient {
+    pub fn new() -> Self {
+        NhatsClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/nhats.api"),
+            dv: None,
+            dur: None,
+            stay: None,
+            launch: None,
+            h: None,
+            occ: None,
+        }
+    }
+
+    /// Maximum total delta-v (km/s), 4 to 12
+    pub fn dv(&mut self, dv: u8) {
+        self.dv = Some(dv)
+    }
+
+    /// Maximum total mission duration (days), 60 to 450 in steps of 30
+    pub fn dur(&mut self, dur: u16) {
+        self.dur = Some(dur)
+    }
+
+    /// Minimum stay at the object (days), one of 8, 16, 24 or 32
+    pub fn stay(&mut self, stay: u8) {
+        self.stay = Some(stay)
+    }
+
+    /// Launch window, e.g. 2025-2030, or 2015-2040 for the full span
+    pub fn launch(&mut self, launch: &str) {
+        self.launch = Some(String::from(launch))
+    }
+
+    /// Maximum absolute magnitude, 16 to 30
+    pub fn h(&mut self, h: u8) {
+        self.h = Some(h)
+    }
+
+    /// Maximum orbit condition code, 0 to 8
+    pub fn occ(&mut self, occ: u8) {
+        self.occ = Some(occ)
+    }
+
+    fn constraints(&self, url: &mut UrlBuilder) -> Result<(), Box<dyn Error>> {
+        if let Some(dv) = self.dv {
+            allowed("dv", DV.contains(&dv), dv)?;
+            url.param("dv", dv);
+        }
+        if let Some(dur) = self.dur {
+            allowed("dur", DUR.contains(&dur), dur)?;
+            url.param("dur", dur);
+        }
+        if let Some(stay) = self.stay {
+            allowed("stay", STAY.contains(&stay), stay)?;
+            url.param("stay", stay);
+        }
+        if let Some(launch) = &self.launch {
+            allowed("launch", LAUNCH.contains(&launch.as_str()), launch)?;
+            url.param("launch", launch);
+        }
+        if let Some(h) = self.h {
+            allowed("h", (16..=30).contains(&h), h)?;
+            url.param("h", h);
+        }
+        if let Some(occ) = self.occ {
+            allowed("occ", occ <= 8, occ)?;
+            url.param("occ", occ);
+        }
+        Ok(())
+    }
+
+    /// Summary url, listing every object that satisfies the constraints
+    pub fn summary_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+        self.constraints(&mut url)?;
+        Ok(url.build())
+    }
+
+    /// Object url, with the trajectories of a single object that satisfy the constraints
+    pub fn object_url(&self, query_type: QueryType, query: &str) -> Result<String, Box<dyn Error>> {
+        if query_type == QueryType::SSTR {
+            return Err("NHATS only accepts des or spk".into());
+        }
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param(query_type.as_param(), query);
+        self.constraints(&mut url)?;
+        Ok(url.build())
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let nhats = into_response(res.as_str())?;
+        check_error(&nhats.json()?)?;
+
+        Ok(nhats)
+    }
+
+    /// NHATS: summary of every accessible object
+    pub fn summary(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.summary_url()?)
+    }
+
+    /// NHATS: trajectories of a single object
+    pub fn query(&self, query_type: QueryType, query: &str) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.object_url(query_type, query)?)
+    }
+
+    /// Summary parsed into [`NhatsObject`] rows
+    pub fn objects(&self) -> Result<Vec<NhatsObject>, Box<dyn Error>> {
+        NhatsObject::from_response(&self.summary()?)
+    }
+
+    /// A single object parsed into a [`NhatsDetail`]
+    pub fn detail(
+        &self,
+        query_type: QueryType,
+        query: &str,
+    ) -> Result<NhatsDetail, Box<dyn Error>> {
+        NhatsDetail::from_response(&self.query(query_type, query)?)
+    }
+}
+
+impl Default for NhatsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn allowed<T: std::fmt::Display>(name: &str, ok: bool, value: T) -> Result<(), Box<dyn Error>> {
+    if ok {
+        Ok(())
+    } else {
+        Err(format!("{} is not a valid NHATS {} constraint", value, name).into())
+    }
+}
+
+/// An accessible object from the NHATS summary.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NhatsObject {
+    /// Object designation
+    pub des: String,
+    /// Full name of the object
+    pub fullname: Option<String>,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Orbit condition code
+    pub occ: Option<u8>,
+    /// Estimated minimum diameter (m)
+    pub min_size: Option<f64>,
+    /// Estimated maximum diameter (m)
+    pub max_size: Option<f64>,
+    /// Number of viable trajectories
+    pub n_via_traj: Option<u32>,
+    /// Total delta-v (km/s) and duration (days) of the lowest delta-v trajectory
+    pub min_dv: Option<(f64, f64)>,
+    /// Total delta-v (km/s) and duration (days) of the shortest trajectory
+    pub min_dur: Option<(f64, f64)>,
+    /// Start of the next optical observing window
+    pub obs_start: Option<String>,
+    /// End of the next optical observing window
+    pub obs_end: Option<String>,
+    /// Peak visual magnitude during the observing window
+    pub obs_mag: Option<f64>,
+}
+
+impl NhatsObject {
+    /// Parses every row of a summary response.
+    pub fn from_response(res: &Response) -> Result<Vec<NhatsObject>, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        records(&json)?.iter().map(NhatsObject::from_json).collect()
+    }
+
+    fn from_json(json: &JsonValue) -> Result<NhatsObject, Box<dyn Error>> {
+        let dv_dur = |key: &str| {
+            let values = json.get(key)?;
+            Some((field_f64(values, "dv")?, field_f64(values, "dur")?))
+        };
+
+        Ok(NhatsObject {
+            des: field_str(json, "des").ok_or("NHATS object is missing its des")?,
+            fullname: field_str(json, "fullname"),
+            h: field_f64(json, "h"),
+            occ: field_f64(json, "occ").map(|occ| occ as u8),
+            min_size: field_f64(json, "min_size"),
+            max_size: field_f64(json, "max_size"),
+            n_via_traj: field_f64(json, "n_via_traj").map(|n| n as u32),
+            min_dv: dv_dur("min_dv"),
+            min_dur: dv_dur("min_dur"),
+            obs_start: field_str(json, "obs_start"),
+            obs_end: field_str(json, "obs_end"),
+            obs_mag: field_f64(json, "obs_mag"),
+        })
+    }
+}
+
+/// Pairs each NHATS object with the [`AccessibleTarget`] of the same designation, e.g. to
+/// compare NHATS round trips with the one-way trajectories Mission Design ranked them by.
+/// Objects missing from either list are left out.
+pub fn join_accessible<'a>(
+    objects: &'a [NhatsObject],
+    targets: &'a [AccessibleTarget],
+) -> Vec<(&'a NhatsObject, &'a AccessibleTarget)> {
+    objects
+        .iter()
+        .filter_map(|object| {
+            let target = targets
+                .iter()
+                .find(|target| designation(&target.name) == designation(&object.des))?;
+            Some((object, target))
+        })
+        .collect()
+}
+
+/// Designation without the parentheses and spacing some names are given, e.g. (2000 SG344)
+fn designation(name: &str) -> String {
+    let name = name.trim();
+    let name = name
+        .strip_prefix('(')
+        .and_then(|name| name.strip_suffix(')'))
+        .unwrap_or(name);
+    name.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Returns the `n` objects with the lowest minimum total delta-v, cheapest first.
+pub fn lowest_delta_v(objects: &[NhatsObject], n: usize) -> Vec<&NhatsObject> {
+    let mut sorted: Vec<&NhatsObject> = objects.iter().filter(|o| o.min_dv.is_some()).collect();
+    sorted.sort_by(|a, b| {
+        let (a_dv, _) = a.min_dv.unwrap_or_default();
+        let (b_dv, _) = b.min_dv.unwrap_or_default();
+        a_dv.total_cmp(&b_dv)
+    });
+    sorted.truncate(n);
+    sorted
+}
+
+/// A single NHATS object with its lowest delta-v and shortest trajectories.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NhatsDetail {
+    pub object: NhatsObject,
+    /// Trajectory with the lowest total delta-v
+    pub min_dv_traj: Option<NhatsTrajectory>,
+    /// Trajectory with the shortest total duration
+    pub min_dur_traj: Option<NhatsTrajectory>,
+}
+
+impl NhatsDetail {
+    /// Parses an object response.
+    pub fn from_response(res: &Response) -> Result<NhatsDetail, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+
+        Ok(NhatsDetail {
+            object: NhatsObject::from_json(&json)?,
+            min_dv_traj: json.get("min_dv_traj").map(NhatsTrajectory::from_json),
+            min_dur_traj: json.get("min_dur_traj").map(NhatsTrajectory::from_json),
+        })
+    }
+}
+
+/// A round-trip trajectory from Earth to the object and back.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NhatsTrajectory {
+    /// Earth departure date
+    pub launch: Option<String>,
+    /// Total delta-v (km/s)
+    pub dv_total: Option<f64>,
+    /// Total mission duration (days)
+    pub dur_total: Option<f64>,
+    /// Outbound leg duration (days)
+    pub dur_out: Option<f64>,
+    /// Stay at the object (days)
+    pub dur_at: Option<f64>,
+    /// Return leg duration (days)
+    pub dur_ret: Option<f64>,
+    /// Departure C3 energy (km^2/s^2)
+    pub c3: Option<f64>,
+    /// Earth departure delta-v (km/s)
+    pub v_dep_earth: Option<f64>,
+    /// Earth return entry velocity (km/s)
+    pub v_arr_earth: Option<f64>,
+}
+
+impl NhatsTrajectory {
+    fn from_json(json: &JsonValue) -> NhatsTrajectory {
+        NhatsTrajectory {
+            launch: field_str(json, "launch"),
+            dv_total: field_f64(json, "dv_total"),
+            dur_total: field_f64(json, "dur_total"),
+            dur_out: field_f64(json, "dur_out"),
+            dur_at: field_f64(json, "dur_at"),
+            dur_ret: field_f64(json, "dur_ret"),
+            c3: field_f64(json, "c3"),
+            v_dep_earth: field_f64(json, "v_dep_earth"),
+            v_arr_earth: field_f64(json, "v_arr_earth"),
+        }
+    }
+}
@@ -0,0 +1,341 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, check_range, field_f64, field_str, records};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL three-body Periodic Orbits API.
+///
+/// States are returned in the normalized rotating frame of the circular restricted three-body
+/// problem (CR3BP) of the selected system, and can be checked locally with
+/// [`PeriodicOrbit::propagate`].
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = PeriodicOrbitsClient::new();
+/// base.sys("earth-moon");
+/// base.family("halo");
+/// base.libr(1);
+/// base.branch("N");
+/// base.jacobi(3.0, 3.1);
+///
+/// let family = base.family_orbits().unwrap();
+/// let orbit = &family.orbits[0];
+/// println!("closure error {}", orbit.closure_error(family.system.mass_ratio, 20000));
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct PeriodicOrbitsClient {
+    base_url: String,
+    sys: Option<String>,
+    family: Option<String>,
+    libr: Option<u8>,
+    branch: Option<String>,
+    jacobi: Option<(f64, f64)>,
+    period: Option<(f64, f64)>,
+    period_units: Option<String>,
+    stability: Option<(f64, f64)>,
+}
+
+impl PeriodicOrbitsClient {
+    pub fn new() -> Self {
+        PeriodicOrbitsClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/periodic_orbits.api"),
+            sys: None,
+            family: None,
+            libr: None,
+            branch: None,
+            jacobi: None,
+            period: None,
+            period_units: None,
+            stability: None,
+        }
+    }
+
+    /// Three-body system, e.g. earth-moon, sun-earth or jupiter-europa
+    pub fn sys(&mut self, sys: &str) {
+        self.sys = Some(String::from(sys))
+    }
+
+    /// Orbit family, e.g. halo, vertical, axial, lyapunov, longp, short, butterfly,
+    /// dragonfly, resonant, dro, dpo or lpo
+    pub fn family(&mut self, family: &str) {
+        self.family = Some(String::from(family))
+    }
+
+    /// Libration point, 1 to 5, required by the libration point families
+    pub fn libr(&mut self, libr: u8) {
+        self.libr = Some(libr)
+    }
+
+    /// Family branch, e.g. N or S for halo orbits, E or W for lpo, pq for resonant orbits
+    pub fn branch(&mut self, branch: &str) {
+        self.branch = Some(String::from(branch))
+    }
+
+    /// Range of the Jacobi constant
+    pub fn jacobi(&mut self, min: f64, max: f64) {
+        self.jacobi = Some((min, max))
+    }
+
+    /// Range of the period, in the units set by [`PeriodicOrbitsClient::period_units`]
+    pub fn period(&mut self, min: f64, max: f64) {
+        self.period = Some((min, max))
+    }
+
+    /// Units of the period range: s, h, d or TU (normalized time units, the default)
+    pub fn period_units(&mut self, units: &str) {
+        self.period_units = Some(String::from(units))
+    }
+
+    /// Range of the stability index
+    pub fn stability(&mut self, min: f64, max: f64) {
+        self.stability = Some((min, max))
+    }
+
+    /// Validates the parameters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let sys = self.sys.as_ref().ok_or("periodic orbits require a sys")?;
+        let family = self
+            .family
+            .as_ref()
+            .ok_or("periodic orbits require a family")?;
+
+        let needs_libr = ["halo", "vertical", "axial", "lyapunov", "longp", "short"];
+        match self.libr {
+            None if needs_libr.contains(&family.as_str()) => {
+                return Err(format!("the {} family requires a libration point", family).into())
+            }
+            Some(libr) if !(1..=5).contains(&libr) => {
+                return Err(format!("libration point {} does not exist", libr).into())
+            }
+            _ => {}
+        }
+        if let Some(units) = &self.period_units {
+            if !["s", "h", "d", "TU"].contains(&units.as_str()) {
+                return Err(format!("{} is not a valid period unit", units).into());
+            }
+        }
+
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("sys", sys).param("family", family);
+        if let Some(libr) = self.libr {
+            url.param("libr", libr);
+        }
+        if let Some(branch) = &self.branch {
+            url.param("branch", branch);
+        }
+        let ranges = [
+            ("jacobi", self.jacobi),
+            ("period", self.period),
+            ("stab", self.stability),
+        ];
+        for (name, range) in ranges.iter() {
+            if let Some((min, max)) = range {
+                check_range(name, Some(*min), Some(*max))?;
+                url.param(&format!("{}min", name), min)
+                    .param(&format!("{}max", name), max);
+            }
+        }
+        if let Some(units) = &self.period_units {
+            url.param("periodunits", units);
+        }
+
+        Ok(url.build())
+    }
+
+    /// Periodic Orbits: query a family
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let orbits = into_response(res.as_str())?;
+        check_error(&orbits.json()?)?;
+
+        Ok(orbits)
+    }
+
+    /// Queries a family and parses it into a [`PeriodicOrbitFamily`]
+    pub fn family_orbits(&self) -> Result<PeriodicOrbitFamily, Box<dyn Error>> {
+        PeriodicOrbitFamily::from_response(&self.query()?)
+    }
+}
+
+impl Default for PeriodicOrbitsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The three-body system periodic orbits are computed in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cr3bpSystem {
+    /// System name, e.g. earth-moon
+    pub name: String,
+    /// Mass ratio of the secondary body to the total mass
+    pub mass_ratio: f64,
+    /// Length unit (km)
+    pub lunit: Option<f64>,
+    /// Time unit (s)
+    pub tunit: Option<f64>,
+}
+
+/// A family of periodic orbits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodicOrbitFamily {
+    pub system: Cr3bpSystem,
+    /// Family name, e.g. halo
+    pub family: Option<String>,
+    /// Libration point the family is associated with
+    pub libration_point: Option<u8>,
+    /// Branch of the family
+    pub branch: Option<String>,
+    pub orbits: Vec<PeriodicOrbit>,
+}
+
+impl PeriodicOrbitFamily {
+    /// Parses a Periodic Orbits response.
+    pub fn from_response(res: &Response) -> Result<PeriodicOrbitFamily, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        let system = json.get("system").ok_or("response has no system")?;
+
+        let orbits = records(&json)?
+            .iter()
+            .map(PeriodicOrbit::from_record)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PeriodicOrbitFamily {
+            system: Cr3bpSystem {
+                name: field_str(system, "name").unwrap_or_default(),
+                mass_ratio: field_f64(system, "mass_ratio").ok_or("system has no mass ratio")?,
+                lunit: field_f64(system, "lunit"),
+                tunit: field_f64(system, "tunit"),
+            },
+            family: field_str(&json, "family"),
+            libration_point: field_f64(&json, "libration_point").map(|libr| libr as u8),
+            branch: field_str(&json, "branch"),
+            orbits,
+        })
+    }
+}
+
+/// Initial state of a periodic orbit, in normalized CR3BP units.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PeriodicOrbit {
+    /// Position and velocity: x, y, z, vx, vy, vz
+    pub state: [f64; 6],
+    /// Jacobi constant
+    pub jacobi: f64,
+    /// Period (TU)
+    pub period: f64,
+    /// Stability index
+    pub stability: Option<f64>,
+}
+
+impl PeriodicOrbit {
+    fn from_record(record: &JsonValue) -> Result<PeriodicOrbit, Box<dyn Error>> {
+        let required =
+            |name: &str| field_f64(record, name).ok_or(format!("periodic orbit has no {}", name));
+
+        Ok(PeriodicOrbit {
+            state: [
+                required("x")?,
+                required("y")?,
+                required("z")?,
+                required("vx")?,
+                required("vy")?,
+                required("vz")?,
+            ],
+            jacobi: required("jacobi")?,
+            period: required("period")?,
+            stability: field_f64(record, "stability"),
+        })
+    }
+
+    /// Propagates the initial state over one period with `steps` fixed RK4 steps, returning
+    /// every intermediate state (including the initial one).
+    pub fn propagate(&self, mass_ratio: f64, steps: usize) -> Vec<[f64; 6]> {
+        propagate_cr3bp(mass_ratio, self.state, self.period, steps)
+    }
+
+    /// Distance between the initial state and the state after one propagated period. A
+    /// correct periodic orbit returns close to zero.
+    pub fn closure_error(&self, mass_ratio: f64, steps: usize) -> f64 {
+        let states = self.propagate(mass_ratio, steps);
+        let last = states.last().unwrap_or(&self.state);
+        self.state
+            .iter()
+            .zip(last.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+            .sqrt()
+    }
+}
+
+/// Jacobi constant of a state in the rotating frame.
+pub fn jacobi_constant(mass_ratio: f64, state: &[f64; 6]) -> f64 {
+    let [x, y, z, vx, vy, vz] = *state;
+    let (r1, r2) = primary_distances(mass_ratio, x, y, z);
+    x * x + y * y + 2.0 * (1.0 - mass_ratio) / r1 + 2.0 * mass_ratio / r2
+        - (vx * vx + vy * vy + vz * vz)
+}
+
+/// Integrates the CR3BP equations of motion from `state` for `duration` time units with `steps`
+/// fixed RK4 steps, returning every intermediate state (including the initial one).
+pub fn propagate_cr3bp(
+    mass_ratio: f64,
+    state: [f64; 6],
+    duration: f64,
+    steps: usize,
+) -> Vec<[f64; 6]> {
+    let steps = steps.max(1);
+    let h = duration / steps as f64;
+
+    let mut states = Vec::with_capacity(steps + 1);
+    states.push(state);
+    let mut current = state;
+    for _ in 0..steps {
+        let k1 = cr3bp_derivative(mass_ratio, &current);
+        let k2 = cr3bp_derivative(mass_ratio, &offset(&current, &k1, h / 2.0));
+        let k3 = cr3bp_derivative(mass_ratio, &offset(&current, &k2, h / 2.0));
+        let k4 = cr3bp_derivative(mass_ratio, &offset(&current, &k3, h));
+        for i in 0..6 {
+            current[i] += h / 6.0 * (k1[i] + 2.0 * k2[i] + 2.0 * k3[i] + k4[i]);
+        }
+        states.push(current);
+    }
+    states
+}
+
+fn primary_distances(mass_ratio: f64, x: f64, y: f64, z: f64) -> (f64, f64) {
+    let r1 = ((x + mass_ratio).powi(2) + y * y + z * z).sqrt();
+    let r2 = ((x - 1.0 + mass_ratio).powi(2) + y * y + z * z).sqrt();
+    (r1, r2)
+}
+
+fn cr3bp_derivative(mass_ratio: f64, state: &[f64; 6]) -> [f64; 6] {
+    let [x, y, z, vx, vy, vz] = *state;
+    let (r1, r2) = primary_distances(mass_ratio, x, y, z);
+    let (r1_3, r2_3) = (r1.powi(3), r2.powi(3));
+    let mu = mass_ratio;
+
+    [
+        vx,
+        vy,
+        vz,
+        2.0 * vy + x - (1.0 - mu) * (x + mu) / r1_3 - mu * (x - 1.0 + mu) / r2_3,
+        -2.0 * vx + y - (1.0 - mu) * y / r1_3 - mu * y / r2_3,
+        -(1.0 - mu) * z / r1_3 - mu * z / r2_3,
+    ]
+}
+
+fn offset(state: &[f64; 6], derivative: &[f64; 6], h: f64) -> [f64; 6] {
+    let mut next = *state;
+    for i in 0..6 {
+        next[i] += h * derivative[i];
+    }
+    next
+}
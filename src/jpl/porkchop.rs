@@ -0,0 +1,293 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, MissionDesignMap, MissionObject};
+use crate::response::*;
+
+/// Quantity stored in each cell of a [`Porkchop`] grid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PorkchopQuantity {
+    /// Departure C3 energy (km^2/s^2)
+    C3Dep,
+    /// Departure hyperbolic excess velocity (km/s)
+    VinfDep,
+    /// Arrival hyperbolic excess velocity (km/s)
+    VinfArr,
+    /// Total delta-v (km/s), see [`Porkchop::delta_v`]
+    DeltaV,
+}
+
+/// A single cell of a [`Porkchop`] grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PorkchopPoint {
+    /// Departure date (MJD)
+    pub departure_mjd: f64,
+    /// Time of flight (days)
+    pub tof: f64,
+    pub c3_dep: Option<f64>,
+    pub vinf_dep: Option<f64>,
+    pub vinf_arr: Option<f64>,
+    pub delta_v: Option<f64>,
+}
+
+/// A run of consecutive departure dates with at least one trajectory under a delta-v budget.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LaunchWindow {
+    /// First departure date of the window (MJD)
+    pub open_mjd: f64,
+    /// Last departure date of the window (MJD)
+    pub close_mjd: f64,
+    /// Lowest delta-v trajectory inside the window
+    pub best: PorkchopPoint,
+}
+
+/// Map mode grid of departure date × time of flight, as used for porkchop plots.
+///
+/// Every grid is indexed `[departure][tof]`, with `None` where the API found no trajectory.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = MissionDesignMap::new();
+/// base.designation("2012 TC4");
+/// base.mjd(58490);
+/// base.span(3652);
+/// base.tof(10, 36);
+/// base.step(2);
+///
+/// let porkchop = base.porkchop().unwrap();
+/// let best = porkchop.minimum(PorkchopQuantity::DeltaV).unwrap();
+/// let windows = porkchop.windows(7.0);
+///
+/// std::fs::write("c3.npy", porkchop.to_npy(PorkchopQuantity::C3Dep)).unwrap();
+/// std::fs::write("porkchop.csv", porkchop.to_csv()).unwrap();
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Porkchop {
+    pub object: Option<MissionObject>,
+    /// Departure dates (MJD)
+    pub departure_mjd: Vec<f64>,
+    /// Times of flight (days)
+    pub tof: Vec<f64>,
+    pub c3_dep: Vec<Vec<Option<f64>>>,
+    pub vinf_dep: Vec<Vec<Option<f64>>>,
+    pub vinf_arr: Vec<Vec<Option<f64>>>,
+    pub dv_dep: Vec<Vec<Option<f64>>>,
+    pub dv_arr: Vec<Vec<Option<f64>>>,
+    pub dv_tot: Vec<Vec<Option<f64>>>,
+}
+
+impl Porkchop {
+    /// Parses a map mode response. Grids the response does not include are left empty.
+    pub fn from_response(res: &Response) -> Result<Porkchop, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+
+        let departure_mjd = axis(&json, "dep_date")?;
+        let tof = axis(&json, "tof")?;
+        let grid = |name: &str| grid(&json, name, departure_mjd.len(), tof.len());
+
+        Ok(Porkchop {
+            object: match json.get("object") {
+                Some(object) => Some(MissionObject::from_json(object)?),
+                None => None,
+            },
+            c3_dep: grid("c3_dep")?,
+            vinf_dep: grid("vinf_dep")?,
+            vinf_arr: grid("vinf_arr")?,
+            dv_dep: grid("dv_dep")?,
+            dv_arr: grid("dv_arr")?,
+            dv_tot: grid("dv_tot")?,
+            departure_mjd,
+            tof,
+        })
+    }
+
+    /// Total delta-v of a cell: `dv_tot` when the response has it, otherwise the sum of the
+    /// departure and arrival delta-v.
+    pub fn delta_v(&self, departure: usize, tof: usize) -> Option<f64> {
+        cell(&self.dv_tot, departure, tof).or_else(|| {
+            Some(cell(&self.dv_dep, departure, tof)? + cell(&self.dv_arr, departure, tof)?)
+        })
+    }
+
+    /// Value of a quantity in a cell
+    pub fn value(&self, quantity: PorkchopQuantity, departure: usize, tof: usize) -> Option<f64> {
+        match quantity {
+            PorkchopQuantity::C3Dep => cell(&self.c3_dep, departure, tof),
+            PorkchopQuantity::VinfDep => cell(&self.vinf_dep, departure, tof),
+            PorkchopQuantity::VinfArr => cell(&self.vinf_arr, departure, tof),
+            PorkchopQuantity::DeltaV => self.delta_v(departure, tof),
+        }
+    }
+
+    /// The cell at the given indices
+    pub fn point(&self, departure: usize, tof: usize) -> PorkchopPoint {
+        PorkchopPoint {
+            departure_mjd: self.departure_mjd[departure],
+            tof: self.tof[tof],
+            c3_dep: cell(&self.c3_dep, departure, tof),
+            vinf_dep: cell(&self.vinf_dep, departure, tof),
+            vinf_arr: cell(&self.vinf_arr, departure, tof),
+            delta_v: self.delta_v(departure, tof),
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.departure_mjd.len()).flat_map(move |d| (0..self.tof.len()).map(move |t| (d, t)))
+    }
+
+    /// Global minimum of a quantity over the whole grid
+    pub fn minimum(&self, quantity: PorkchopQuantity) -> Option<PorkchopPoint> {
+        self.cells()
+            .filter_map(|(d, t)| Some((self.value(quantity, d, t)?, d, t)))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, d, t)| self.point(d, t))
+    }
+
+    /// Every cell whose total delta-v is within the budget (km/s)
+    pub fn feasible(&self, dv_budget: f64) -> Vec<PorkchopPoint> {
+        self.cells()
+            .filter(|&(d, t)| matches!(self.delta_v(d, t), Some(dv) if dv <= dv_budget))
+            .map(|(d, t)| self.point(d, t))
+            .collect()
+    }
+
+    /// Launch windows: runs of consecutive departure dates with at least one trajectory
+    /// within the delta-v budget (km/s), each with its cheapest trajectory.
+    pub fn windows(&self, dv_budget: f64) -> Vec<LaunchWindow> {
+        let mut windows: Vec<LaunchWindow> = Vec::new();
+        let mut open = false;
+        for d in 0..self.departure_mjd.len() {
+            let best = (0..self.tof.len())
+                .filter_map(|t| Some((self.delta_v(d, t)?, t)))
+                .filter(|&(dv, _)| dv <= dv_budget)
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+
+            match (best, windows.last_mut()) {
+                (Some((dv, t)), Some(window)) if open => {
+                    window.close_mjd = self.departure_mjd[d];
+                    if window.best.delta_v.map_or(true, |best| dv < best) {
+                        window.best = self.point(d, t);
+                    }
+                }
+                (Some((_, t)), _) => {
+                    windows.push(LaunchWindow {
+                        open_mjd: self.departure_mjd[d],
+                        close_mjd: self.departure_mjd[d],
+                        best: self.point(d, t),
+                    });
+                    open = true;
+                }
+                (None, _) => open = false,
+            }
+        }
+        windows
+    }
+
+    /// Grid in long CSV form, one row per cell:
+    /// `departure_mjd,tof,c3_dep,vinf_dep,vinf_arr,delta_v`, with empty missing values.
+    pub fn to_csv(&self) -> String {
+        let format = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+
+        let mut csv = String::from("departure_mjd,tof,c3_dep,vinf_dep,vinf_arr,delta_v\n");
+        for (d, t) in self.cells() {
+            let point = self.point(d, t);
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                point.departure_mjd,
+                point.tof,
+                format(point.c3_dep),
+                format(point.vinf_dep),
+                format(point.vinf_arr),
+                format(point.delta_v)
+            ));
+        }
+        csv
+    }
+
+    /// One quantity as a NumPy `.npy` file (format 1.0): a little-endian float64 array of shape
+    /// `(departures, tofs)`, with NaN for missing cells.
+    pub fn to_npy(&self, quantity: PorkchopQuantity) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}",
+            self.departure_mjd.len(),
+            self.tof.len()
+        );
+        // magic (6) + version (2) + header length (2) + header must be a multiple of 64
+        let padding = 64 - (10 + header.len() + 1) % 64;
+        header.push_str(&" ".repeat(padding % 64));
+        header.push('\n');
+
+        let mut npy = b"\x93NUMPY\x01\x00".to_vec();
+        npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        npy.extend_from_slice(header.as_bytes());
+        for (d, t) in self.cells() {
+            let value = self.value(quantity, d, t).unwrap_or(f64::NAN);
+            npy.extend_from_slice(&value.to_le_bytes());
+        }
+        npy
+    }
+}
+
+impl MissionDesignMap {
+    /// Queries the map and parses it into a [`Porkchop`] grid
+    pub fn porkchop(&self) -> Result<Porkchop, Box<dyn Error>> {
+        Porkchop::from_response(&self.query()?)
+    }
+}
+
+fn cell(grid: &[Vec<Option<f64>>], departure: usize, tof: usize) -> Option<f64> {
+    *grid.get(departure)?.get(tof)?
+}
+
+fn number(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::String(s) => s.trim().parse().ok(),
+        JsonValue::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+fn axis(json: &JsonValue, name: &str) -> Result<Vec<f64>, Box<dyn Error>> {
+    json.get(name)
+        .and_then(JsonValue::as_array)
+        .ok_or(format!("map has no {} axis", name))?
+        .iter()
+        .map(|value| {
+            number(value).ok_or_else(|| format!("invalid {} value {}", name, value).into())
+        })
+        .collect()
+}
+
+/// Reads a grid in the API's `[departure][tof]` order: one row per departure date, each with
+/// one value per time of flight.
+fn grid(
+    json: &JsonValue,
+    name: &str,
+    departures: usize,
+    tofs: usize,
+) -> Result<Vec<Vec<Option<f64>>>, Box<dyn Error>> {
+    let rows = match json.get(name).and_then(JsonValue::as_array) {
+        Some(rows) => rows,
+        None => return Ok(Vec::new()),
+    };
+    let rows = rows
+        .iter()
+        .map(|row| {
+            row.as_array()
+                .map(|row| row.iter().map(number).collect::<Vec<_>>())
+                .ok_or(format!("{} row is not an array", name))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if rows.len() != departures || rows.iter().any(|row| row.len() != tofs) {
+        return Err(format!(
+            "{} grid does not match the {} x {} axes",
+            name, departures, tofs
+        )
+        .into());
+    }
+    Ok(rows)
+}
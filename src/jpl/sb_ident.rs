@@ -0,0 +1,276 @@
+use std::error::Error;
+
+use serde_json::{json, Value as JsonValue};
+
+use super::{check_error, field_f64, field_str, records};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Small-Body Identification API (sb_ident), which lists the small
+/// bodies inside a field of view at a given time.
+///
+/// The observer, observation time and field center must be set before querying.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = SbIdentClient::new();
+/// base.observer(Observer::MpcCode(String::from("568")));
+/// base.obs_time("2022-01-01_12:00:00");
+/// base.fov_center(150.0, -10.5);
+/// base.fov_size(0.5, 0.5);
+///
+/// let bodies = base.identify().unwrap();
+/// for body in &bodies.first_pass {
+///     println!("{} V={:?}", body.name, body.vmag);
+/// }
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct SbIdentClient {
+    base_url: String,
+    observer: Option<Observer>,
+    obs_time: Option<String>,
+    fov_center: Option<(f64, f64)>,
+    fov_size: Option<(f64, f64)>,
+    two_pass: Option<bool>,
+    vmag_lim: Option<f64>,
+}
+
+/// Location of the observer.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Observer {
+    /// Minor Planet Center observatory code, e.g. 568 (Mauna Kea)
+    MpcCode(String),
+    /// Geodetic latitude and longitude (deg, east positive) and altitude (km)
+    Topocentric { lat: f64, lon: f64, alt: f64 },
+}
+
+impl SbIdentClient {
+    pub fn new() -> Self {
+        SbIdentClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/sb_ident.api"),
+            observer: None,
+            obs_time: None,
+            fov_center: None,
+            fov_size: None,
+            two_pass: None,
+            vmag_lim: None,
+        }
+    }
+
+    pub fn observer(&mut self, observer: Observer) {
+        self.observer = Some(observer)
+    }
+
+    /// Observation time (UTC), formatted YYYY-MM-DD_hh:mm:ss, or a Julian Date
+    pub fn obs_time(&mut self, obs_time: &str) {
+        self.obs_time = Some(String::from(obs_time))
+    }
+
+    /// Center of the field of view, right ascension and declination in degrees
+    pub fn fov_center(&mut self, ra: f64, dec: f64) {
+        self.fov_center = Some((ra, dec))
+    }
+
+    /// Half-widths of the field of view in right ascension and declination, in degrees
+    pub fn fov_size(&mut self, ra_hwidth: f64, dec_hwidth: f64) {
+        self.fov_size = Some((ra_hwidth, dec_hwidth))
+    }
+
+    /// Refine the first pass results with numerical integration (slower, more accurate)
+    pub fn two_pass(&mut self, two_pass: bool) {
+        self.two_pass = Some(two_pass)
+    }
+
+    /// Exclude bodies fainter than this visual magnitude
+    pub fn vmag_lim(&mut self, vmag_lim: f64) {
+        self.vmag_lim = Some(vmag_lim)
+    }
+
+    /// Validates the parameters and builds the query url.
+    pub fn url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+
+        match self
+            .observer
+            .as_ref()
+            .ok_or("sb_ident requires an observer")?
+        {
+            Observer::MpcCode(code) => {
+                url.param("mpc-code", code);
+            }
+            Observer::Topocentric { lat, lon, alt } => {
+                if !(-90.0..=90.0).contains(lat) {
+                    return Err(format!("latitude {} is out of range", lat).into());
+                }
+                if !(-180.0..=360.0).contains(lon) {
+                    return Err(format!("longitude {} is out of range", lon).into());
+                }
+                url.param("lat", lat).param("lon", lon).param("alt", alt);
+            }
+        }
+
+        let obs_time = self
+            .obs_time
+            .as_ref()
+            .ok_or("sb_ident requires an obs-time")?;
+        url.param("obs-time", obs_time);
+
+        let (ra, dec) = self.fov_center.ok_or("sb_ident requires a field center")?;
+        if !(0.0..360.0).contains(&ra) || !(-90.0..=90.0).contains(&dec) {
+            return Err(format!("field center ({}, {}) is out of range", ra, dec).into());
+        }
+        url.param("fov-ra-center", sexagesimal_ra(ra))
+            .param("fov-dec-center", sexagesimal_dec(dec));
+
+        if let Some((ra_hwidth, dec_hwidth)) = self.fov_size {
+            if ra_hwidth <= 0.0 || dec_hwidth <= 0.0 {
+                return Err("field half-widths must be positive".into());
+            }
+            url.param("fov-ra-hwidth", ra_hwidth)
+                .param("fov-dec-hwidth", dec_hwidth);
+        }
+        if let Some(two_pass) = self.two_pass {
+            url.param("two-pass", two_pass);
+        }
+        if let Some(vmag_lim) = self.vmag_lim {
+            url.param("vmag-lim", vmag_lim);
+        }
+
+        Ok(url.build())
+    }
+
+    /// sb_ident: identify the small bodies in the field
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        let url = self.url()?;
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let ident = into_response(res.as_str())?;
+        check_error(&ident.json()?)?;
+
+        Ok(ident)
+    }
+
+    /// Identifies the small bodies in the field and parses them into [`SbIdentResult`]
+    pub fn identify(&self) -> Result<SbIdentResult, Box<dyn Error>> {
+        SbIdentResult::from_response(&self.query()?)
+    }
+}
+
+impl Default for SbIdentClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Right ascension in degrees as hh-mm-ss.ss
+fn sexagesimal_ra(ra: f64) -> String {
+    let (h, m, s) = sexagesimal(ra / 15.0);
+    format!("{:02}-{:02}-{:05.2}", h, m, s)
+}
+
+/// Declination in degrees as dd-mm-ss.ss, with an M prefix for negative values
+fn sexagesimal_dec(dec: f64) -> String {
+    let (d, m, s) = sexagesimal(dec.abs());
+    let sign = if dec < 0.0 { "M" } else { "" };
+    format!("{}{:02}-{:02}-{:05.2}", sign, d, m, s)
+}
+
+fn sexagesimal(value: f64) -> (u32, u32, f64) {
+    // round to the printed precision first so 59.999 seconds never shows up as 60.00
+    let hundredths = (value * 360000.0).round() as u64;
+    let whole = (hundredths / 360000) as u32;
+    let minutes = ((hundredths / 6000) % 60) as u32;
+    let seconds = (hundredths % 6000) as f64 / 100.0;
+    (whole, minutes, seconds)
+}
+
+/// Small bodies found in the field, from the first (fast) pass and, if requested, the
+/// second (integrated) pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SbIdentResult {
+    pub first_pass: Vec<IdentifiedBody>,
+    pub second_pass: Vec<IdentifiedBody>,
+}
+
+impl SbIdentResult {
+    /// Parses an sb_ident response.
+    pub fn from_response(res: &Response) -> Result<SbIdentResult, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+
+        Ok(SbIdentResult {
+            first_pass: pass(&json, "fields_first", "data_first_pass")?,
+            second_pass: pass(&json, "fields_second", "data_second_pass")?,
+        })
+    }
+}
+
+/// Reads one pass, e.g. `fields_first` with `data_first_pass`. The API names the two keys
+/// differently.
+fn pass(json: &JsonValue, fields: &str, data: &str) -> Result<Vec<IdentifiedBody>, Box<dyn Error>> {
+    let table = json!({
+        "fields": json.get(fields),
+        "data": json.get(data),
+    });
+    records(&table)?
+        .iter()
+        .map(IdentifiedBody::from_record)
+        .collect()
+}
+
+/// A small body inside the field of view, with its predicted position and brightness.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IdentifiedBody {
+    /// Object name, e.g. "433 Eros (A898 PA)"
+    pub name: String,
+    /// Astrometric right ascension, hh:mm:ss
+    pub ra: Option<String>,
+    /// Astrometric declination, dd mm'ss"
+    pub dec: Option<String>,
+    /// Offset from the field center in right ascension (arcsec)
+    pub dist_ra: Option<f64>,
+    /// Offset from the field center in declination (arcsec)
+    pub dist_dec: Option<f64>,
+    /// Distance from the field center (arcsec)
+    pub dist_norm: Option<f64>,
+    /// Visual magnitude
+    pub vmag: Option<f64>,
+    /// Rate of motion in right ascension (arcsec/h)
+    pub ra_rate: Option<f64>,
+    /// Rate of motion in declination (arcsec/h)
+    pub dec_rate: Option<f64>,
+    /// Estimated position error in right ascension (arcsec)
+    pub err_ra: Option<f64>,
+    /// Estimated position error in declination (arcsec)
+    pub err_dec: Option<f64>,
+}
+
+impl IdentifiedBody {
+    fn from_record(record: &JsonValue) -> Result<IdentifiedBody, Box<dyn Error>> {
+        // sb_ident field names carry their units, e.g. `RA rate ("/h)`, so match on prefixes
+        let key = |prefix: &str| {
+            record
+                .as_object()
+                .and_then(|fields| fields.keys().find(|key| key.starts_with(prefix)))
+                .cloned()
+                .unwrap_or_default()
+        };
+        let number = |prefix: &str| field_f64(record, &key(prefix));
+        let text = |prefix: &str| field_str(record, &key(prefix));
+
+        Ok(IdentifiedBody {
+            name: text("Object name").ok_or("identified body has no name")?,
+            ra: text("Astrometric RA"),
+            dec: text("Astrometric Dec"),
+            dist_ra: number("Dist. from center RA"),
+            dist_dec: number("Dist. from center Dec"),
+            dist_norm: number("Dist. from center Norm"),
+            vmag: number("Visual magnitude"),
+            ra_rate: number("RA rate"),
+            dec_rate: number("Dec rate"),
+            err_ra: number("Est. error RA"),
+            err_dec: number("Est. error Dec"),
+        })
+    }
+}
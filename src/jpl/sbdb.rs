@@ -0,0 +1,430 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, field_f64, field_str, QueryType};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Small-Body Database (SBDB) API.
+///
+/// Objects are selected with the same [`QueryType`] used by [`MissionDesign`](super::MissionDesign),
+/// so a designation looked up here can be passed straight to a mission design query.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = SbdbClient::new();
+/// base.phys_par(true);
+/// base.ca_data(true);
+///
+/// let eros = base.object(QueryType::DES, "433").unwrap();
+/// println!("a = {:?} au, e = {}", eros.orbit.a, eros.orbit.e);
+///
+/// let mission = MissionDesign::new().query(QueryType::DES, &eros.des).unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct SbdbClient {
+    base_url: String,
+    phys_par: Option<bool>,
+    ca_data: Option<bool>,
+    discovery: Option<bool>,
+    alt_des: Option<bool>,
+    cov: Option<SbdbCovariance>,
+}
+
+/// Format of the orbit covariance requested from SBDB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SbdbCovariance {
+    /// Full covariance matrix
+    Mat,
+    /// Square-root covariance, packed as a flat upper triangle
+    Src,
+}
+
+impl SbdbClient {
+    pub fn new() -> Self {
+        SbdbClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/sbdb.api"),
+            phys_par: None,
+            ca_data: None,
+            discovery: None,
+            alt_des: None,
+            cov: None,
+        }
+    }
+
+    /// Include physical parameters (H, diameter, albedo, rotation period, ...)
+    pub fn phys_par(&mut self, phys_par: bool) {
+        self.phys_par = Some(phys_par)
+    }
+
+    /// Include close-approach data
+    pub fn ca_data(&mut self, ca_data: bool) {
+        self.ca_data = Some(ca_data)
+    }
+
+    /// Include discovery circumstances
+    pub fn discovery(&mut self, discovery: bool) {
+        self.discovery = Some(discovery)
+    }
+
+    /// Include alternate designations
+    pub fn alt_des(&mut self, alt_des: bool) {
+        self.alt_des = Some(alt_des)
+    }
+
+    /// Include the orbit covariance in the given format
+    pub fn cov(&mut self, cov: SbdbCovariance) {
+        self.cov = Some(cov)
+    }
+
+    /// Builds the query url for an object.
+    pub fn url(&self, query_type: QueryType, query: &str) -> String {
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param(query_type.as_param(), query);
+
+        let flags = [
+            ("phys-par", self.phys_par),
+            ("ca-data", self.ca_data),
+            ("discovery", self.discovery),
+            ("alt-des", self.alt_des),
+        ];
+        for (name, value) in flags.iter() {
+            if let Some(value) = value {
+                url.param(name, *value as u8);
+            }
+        }
+        match self.cov {
+            Some(SbdbCovariance::Mat) => {
+                url.param("cov", "mat");
+            }
+            Some(SbdbCovariance::Src) => {
+                url.param("cov", "src");
+            }
+            None => {}
+        }
+        url.build()
+    }
+
+    /// SBDB: look up a single object
+    pub fn query(&self, query_type: QueryType, query: &str) -> Result<Response, Box<dyn Error>> {
+        let url = self.url(query_type, query);
+
+        let res: String = ureq::get(&url).call()?.into_string()?;
+        let sbdb = into_response(res.as_str())?;
+
+        Ok(sbdb)
+    }
+
+    /// Looks up a single object and parses it into a [`SbdbObject`].
+    pub fn object(&self, query_type: QueryType, query: &str) -> Result<SbdbObject, Box<dyn Error>> {
+        SbdbObject::from_response(&self.query(query_type, query)?)
+    }
+}
+
+impl Default for SbdbClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A small body from the SBDB, with its orbit and any optional data that was requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SbdbObject {
+    /// Primary designation, usable with [`QueryType::DES`]
+    pub des: String,
+    /// SPK-ID, usable with [`QueryType::SPK`]
+    pub spkid: Option<String>,
+    /// Full name, e.g. "433 Eros (A898 PA)"
+    pub fullname: Option<String>,
+    /// Object kind: an/au (numbered/unnumbered asteroid), cn/cu (numbered/unnumbered comet)
+    pub kind: Option<String>,
+    /// Orbit class code, e.g. AMO
+    pub orbit_class: Option<String>,
+    /// Near-Earth object flag
+    pub neo: bool,
+    /// Potentially hazardous asteroid flag
+    pub pha: bool,
+    /// Osculating orbital elements
+    pub orbit: OrbitElements,
+    /// Physical parameters, when `phys-par` was requested
+    pub phys_par: Vec<PhysicalParameter>,
+    /// Close approaches, when `ca-data` was requested
+    pub close_approaches: Vec<CloseApproach>,
+    /// Discovery circumstances, when `discovery` was requested
+    pub discovery: Option<Discovery>,
+    /// Alternate designations, when `alt-des` was requested
+    pub alt_des: Vec<String>,
+    /// Orbit covariance, when `cov` was requested
+    pub covariance: Option<Covariance>,
+}
+
+impl SbdbObject {
+    /// Parses an SBDB response. A search string matching several objects is reported as an
+    /// error listing the matches.
+    pub fn from_response(res: &Response) -> Result<SbdbObject, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        if let Some(list) = json.get("list").and_then(JsonValue::as_array) {
+            let names: Vec<String> = list.iter().filter_map(|m| field_str(m, "pdes")).collect();
+            return Err(
+                format!("search matched more than one object: {}", names.join(", ")).into(),
+            );
+        }
+        let object = match json.get("object") {
+            Some(object) => object,
+            None => {
+                let message = field_str(&json, "message")
+                    .unwrap_or_else(|| String::from("no object in SBDB response"));
+                return Err(message.into());
+            }
+        };
+        let orbit = json.get("orbit").ok_or("SBDB response has no orbit")?;
+
+        let phys_par = array(&json, "phys_par")
+            .iter()
+            .map(PhysicalParameter::from_json)
+            .collect();
+        let close_approaches = array(&json, "ca_data")
+            .iter()
+            .map(CloseApproach::from_json)
+            .collect();
+        let alt_des = array(object, "des_alt")
+            .iter()
+            .filter_map(|alt| field_str(alt, "des").or_else(|| alt.as_str().map(String::from)))
+            .collect();
+
+        Ok(SbdbObject {
+            des: field_str(object, "des").ok_or("SBDB object is missing its des")?,
+            spkid: field_str(object, "spkid"),
+            fullname: field_str(object, "fullname"),
+            kind: field_str(object, "kind"),
+            orbit_class: object
+                .get("orbit_class")
+                .and_then(|class| field_str(class, "code")),
+            neo: object
+                .get("neo")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false),
+            pha: object
+                .get("pha")
+                .and_then(JsonValue::as_bool)
+                .unwrap_or(false),
+            orbit: OrbitElements::from_json(orbit)?,
+            phys_par,
+            close_approaches,
+            discovery: json.get("discovery").map(Discovery::from_json),
+            alt_des,
+            covariance: orbit
+                .get("covariance")
+                .map(Covariance::from_json)
+                .transpose()?,
+        })
+    }
+}
+
+/// Heliocentric osculating orbital elements (ecliptic J2000) at an epoch.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrbitElements {
+    /// Epoch of the elements, Julian Date (TDB)
+    pub epoch: f64,
+    /// Eccentricity
+    pub e: f64,
+    /// Semi-major axis (au), absent for parabolic orbits and negative for hyperbolic ones
+    pub a: Option<f64>,
+    /// Perihelion distance (au)
+    pub q: f64,
+    /// Inclination (deg)
+    pub i: f64,
+    /// Longitude of the ascending node (deg)
+    pub om: f64,
+    /// Argument of perihelion (deg)
+    pub w: f64,
+    /// Mean anomaly at epoch (deg), absent for some comets
+    pub ma: Option<f64>,
+    /// Time of perihelion passage, Julian Date (TDB)
+    pub tp: Option<f64>,
+}
+
+impl OrbitElements {
+    fn from_json(orbit: &JsonValue) -> Result<OrbitElements, Box<dyn Error>> {
+        let elements = orbit
+            .get("elements")
+            .and_then(JsonValue::as_array)
+            .ok_or("SBDB orbit has no elements")?;
+        let element = |name: &str| {
+            elements
+                .iter()
+                .find(|element| element.get("name").and_then(JsonValue::as_str) == Some(name))
+                .and_then(|element| field_f64(element, "value"))
+        };
+        let required =
+            |name: &str| element(name).ok_or(format!("SBDB orbit is missing element {}", name));
+
+        Ok(OrbitElements {
+            epoch: field_f64(orbit, "epoch").ok_or("SBDB orbit is missing its epoch")?,
+            e: required("e")?,
+            a: element("a"),
+            q: required("q")?,
+            i: required("i")?,
+            om: required("om")?,
+            w: required("w")?,
+            ma: element("ma"),
+            tp: element("tp"),
+        })
+    }
+}
+
+/// A physical parameter of an object, e.g. H, diameter or albedo.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhysicalParameter {
+    /// Parameter name, e.g. "diameter"
+    pub name: String,
+    /// Value as reported by SBDB (not every parameter is numeric)
+    pub value: Option<String>,
+    /// One-sigma uncertainty
+    pub sigma: Option<String>,
+    /// Units, e.g. "km"
+    pub units: Option<String>,
+}
+
+impl PhysicalParameter {
+    fn from_json(json: &JsonValue) -> PhysicalParameter {
+        PhysicalParameter {
+            name: field_str(json, "name").unwrap_or_default(),
+            value: field_str(json, "value"),
+            sigma: field_str(json, "sigma"),
+            units: field_str(json, "units"),
+        }
+    }
+}
+
+/// A close approach of an object to a planet or the Moon.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CloseApproach {
+    /// Calendar date of the approach (TDB), e.g. "2029-Apr-13 21:46"
+    pub cd: Option<String>,
+    /// Julian Date of the approach (TDB)
+    pub jd: Option<f64>,
+    /// Body approached, e.g. "Earth"
+    pub body: Option<String>,
+    /// Nominal approach distance (au)
+    pub dist: Option<f64>,
+    /// Minimum three-sigma approach distance (au)
+    pub dist_min: Option<f64>,
+    /// Maximum three-sigma approach distance (au)
+    pub dist_max: Option<f64>,
+    /// Relative velocity at approach (km/s)
+    pub v_rel: Option<f64>,
+}
+
+impl CloseApproach {
+    fn from_json(json: &JsonValue) -> CloseApproach {
+        CloseApproach {
+            cd: field_str(json, "cd"),
+            jd: field_f64(json, "jd"),
+            body: field_str(json, "body"),
+            dist: field_f64(json, "dist"),
+            dist_min: field_f64(json, "dist_min"),
+            dist_max: field_f64(json, "dist_max"),
+            v_rel: field_f64(json, "v_rel"),
+        }
+    }
+}
+
+/// Discovery circumstances of an object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Discovery {
+    /// Discovery date
+    pub date: Option<String>,
+    /// Discovery location
+    pub location: Option<String>,
+    /// Discoverer(s)
+    pub who: Option<String>,
+    /// Discovery summary
+    pub discovery: Option<String>,
+}
+
+impl Discovery {
+    fn from_json(json: &JsonValue) -> Discovery {
+        Discovery {
+            date: field_str(json, "date"),
+            location: field_str(json, "location"),
+            who: field_str(json, "who"),
+            discovery: field_str(json, "discovery"),
+        }
+    }
+}
+
+/// Orbit covariance, labeled by element.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Covariance {
+    /// Epoch of the covariance, Julian Date (TDB)
+    pub epoch: Option<f64>,
+    /// Element labels of the rows and columns
+    pub labels: Vec<String>,
+    /// Covariance matrix rows, when `mat` was requested
+    pub data: Vec<Vec<f64>>,
+    /// Square-root covariance as SBDB packs it, a flat upper triangle of
+    /// `n * (n + 1) / 2` values for `n` labels, when `src` was requested
+    pub src: Vec<f64>,
+}
+
+impl Covariance {
+    fn from_json(json: &JsonValue) -> Result<Covariance, Box<dyn Error>> {
+        let labels: Vec<String> = array(json, "labels")
+            .iter()
+            .filter_map(|l| l.as_str().map(String::from))
+            .collect();
+        let values = array(json, "data");
+
+        // a matrix is an array of rows, the square-root covariance a single flat vector
+        let (data, src) = if values.iter().all(JsonValue::is_array) {
+            let rows = values
+                .iter()
+                .filter_map(JsonValue::as_array)
+                .map(|row| covariance_values(row))
+                .collect::<Result<Vec<_>, _>>()?;
+            (rows, Vec::new())
+        } else {
+            let src = covariance_values(values)?;
+            let n = labels.len();
+            if n > 0 && src.len() != n * (n + 1) / 2 {
+                return Err(format!(
+                    "square-root covariance has {} values, expected {} for {} labels",
+                    src.len(),
+                    n * (n + 1) / 2,
+                    n
+                )
+                .into());
+            }
+            (Vec::new(), src)
+        };
+
+        Ok(Covariance {
+            epoch: field_f64(json, "epoch"),
+            labels,
+            data,
+            src,
+        })
+    }
+}
+
+fn covariance_values(values: &[JsonValue]) -> Result<Vec<f64>, Box<dyn Error>> {
+    values
+        .iter()
+        .map(|value| match value {
+            JsonValue::String(s) => s.trim().parse::<f64>().map_err(|e| e.into()),
+            JsonValue::Number(n) => n.as_f64().ok_or_else(|| "invalid covariance value".into()),
+            _ => Err("invalid covariance value".into()),
+        })
+        .collect()
+}
+
+fn array<'a>(json: &'a JsonValue, key: &str) -> &'a [JsonValue] {
+    json.get(key)
+        .and_then(JsonValue::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or(&[])
+}
@@ -0,0 +1,405 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, field_f64, field_str, records, OrbitElements};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Scout API, which assesses unconfirmed objects on the Minor Planet
+/// Center's NEO Confirmation Page (NEOCP).
+///
+/// The ephemeris options (eph-start, eph-stop, eph-step, obs-code) apply to the object and plan
+/// queries, and orbits only to the object query.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = ScoutClient::new();
+/// let candidates = base.objects().unwrap();
+///
+/// // Follow up the highest rated candidates first
+/// let first = &prioritize_scout(&candidates)[0];
+///
+/// base.orbits(true);
+/// base.obs_code("568");
+/// base.eph_start("2022-01-01T00:00:00");
+/// let detail = base.detail(&first.object_name).unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct ScoutClient {
+    base_url: String,
+    orbits: Option<bool>,
+    eph_start: Option<String>,
+    eph_stop: Option<String>,
+    eph_step: Option<String>,
+    obs_code: Option<String>,
+}
+
+impl ScoutClient {
+    pub fn new() -> Self {
+        ScoutClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/scout.api"),
+            orbits: None,
+            eph_start: None,
+            eph_stop: None,
+            eph_step: None,
+            obs_code: None,
+        }
+    }
+
+    /// Include the sampled orbits of the object
+    pub fn orbits(&mut self, orbits: bool) {
+        self.orbits = Some(orbits)
+    }
+
+    /// Start time of the ephemeris (UTC), e.g. 2022-01-01T00:00:00 or "now"
+    pub fn eph_start(&mut self, eph_start: &str) {
+        self.eph_start = Some(String::from(eph_start))
+    }
+
+    /// Stop time of the ephemeris (UTC)
+    pub fn eph_stop(&mut self, eph_stop: &str) {
+        self.eph_stop = Some(String::from(eph_stop))
+    }
+
+    /// Ephemeris step size, e.g. 1h or 30m
+    pub fn eph_step(&mut self, eph_step: &str) {
+        self.eph_step = Some(String::from(eph_step))
+    }
+
+    /// MPC code of the observatory the ephemeris is computed for, e.g. 568
+    pub fn obs_code(&mut self, obs_code: &str) {
+        self.obs_code = Some(String::from(obs_code))
+    }
+
+    fn ephemeris_params(&self, url: &mut UrlBuilder) -> Result<(), Box<dyn Error>> {
+        if self.eph_start.is_none() && (self.eph_stop.is_some() || self.eph_step.is_some()) {
+            return Err("eph-stop and eph-step require eph-start".into());
+        }
+
+        let options = [
+            ("eph-start", &self.eph_start),
+            ("eph-stop", &self.eph_stop),
+            ("eph-step", &self.eph_step),
+            ("obs-code", &self.obs_code),
+        ];
+        for (name, value) in options.iter() {
+            if let Some(value) = value {
+                url.param(name, value);
+            }
+        }
+        Ok(())
+    }
+
+    /// Summary url of every object on the NEOCP
+    pub fn summary_url(&self) -> String {
+        self.base_url.clone()
+    }
+
+    /// Detail url of a single object, identified by its temporary designation
+    pub fn detail_url(&self, tdes: &str) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("tdes", tdes);
+        if self.orbits == Some(true) {
+            url.param("orbits", 1);
+        }
+        self.ephemeris_params(&mut url)?;
+        Ok(url.build())
+    }
+
+    /// Observation planning url, listing every object observable from `obs-code`
+    pub fn plan_url(&self) -> Result<String, Box<dyn Error>> {
+        if self.obs_code.is_none() {
+            return Err("plan mode requires obs-code".into());
+        }
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("plan", 1);
+        self.ephemeris_params(&mut url)?;
+        Ok(url.build())
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let scout = into_response(res.as_str())?;
+        check_error(&scout.json()?)?;
+
+        Ok(scout)
+    }
+
+    /// Scout: summary of every object
+    pub fn summary(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.summary_url())
+    }
+
+    /// Scout: a single object, with orbits and ephemeris when requested
+    pub fn query(&self, tdes: &str) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.detail_url(tdes)?)
+    }
+
+    /// Scout: observation planning for `obs-code`
+    pub fn plan(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.plan_url()?)
+    }
+
+    /// Summary parsed into [`ScoutObject`] rows
+    pub fn objects(&self) -> Result<Vec<ScoutObject>, Box<dyn Error>> {
+        ScoutObject::from_response(&self.summary()?)
+    }
+
+    /// A single object parsed into a [`ScoutDetail`]
+    pub fn detail(&self, tdes: &str) -> Result<ScoutDetail, Box<dyn Error>> {
+        ScoutDetail::from_response(&self.query(tdes)?)
+    }
+}
+
+impl Default for ScoutClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An object on the NEO Confirmation Page, as summarized by Scout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutObject {
+    /// Temporary designation, e.g. P21Xyz1
+    pub object_name: String,
+    /// Follow-up rating assigned by Scout, higher is more urgent
+    pub rating: Option<u8>,
+    /// Probability (0-100) that the object is a NEO
+    pub neo_score: Option<f64>,
+    /// Probability (0-100) that the object is a NEO larger than 1 km
+    pub neo1km_score: Option<f64>,
+    /// Probability (0-100) that the object is a PHA
+    pub pha_score: Option<f64>,
+    /// Probability (0-100) that the object is an interior-Earth object
+    pub ieo_score: Option<f64>,
+    /// Probability (0-100) that the object is in a geocentric orbit
+    pub geocentric_score: Option<f64>,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Current visual magnitude
+    pub vmag: Option<f64>,
+    /// Right ascension, e.g. "12:34"
+    pub ra: Option<String>,
+    /// Declination, e.g. "+01"
+    pub dec: Option<String>,
+    /// Solar elongation (deg)
+    pub elong: Option<f64>,
+    /// Rate of motion (arcsec/min)
+    pub rate: Option<f64>,
+    /// Plane-of-sky uncertainty (arcmin)
+    pub unc: Option<f64>,
+    /// Plane-of-sky uncertainty one day from now (arcmin)
+    pub unc_p1: Option<f64>,
+    /// Number of observations
+    pub n_obs: Option<u32>,
+    /// Observation arc (days)
+    pub arc: Option<f64>,
+    /// Normalized RMS of the fit
+    pub rms_n: Option<f64>,
+    /// Earth MOID (au)
+    pub moid: Option<f64>,
+    /// Close-approach distance (lunar distances)
+    pub ca_dist: Option<f64>,
+    /// Hyperbolic excess velocity (km/s)
+    pub v_inf: Option<f64>,
+    /// Time of the ephemeris values (UTC)
+    pub t_ephem: Option<String>,
+    /// Time Scout last analyzed the object (UTC)
+    pub last_run: Option<String>,
+}
+
+impl ScoutObject {
+    /// Parses every row of a summary (or plan) response.
+    pub fn from_response(res: &Response) -> Result<Vec<ScoutObject>, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        records(&json)?
+            .iter()
+            .map(ScoutObject::from_record)
+            .collect()
+    }
+
+    fn from_record(record: &JsonValue) -> Result<ScoutObject, Box<dyn Error>> {
+        Ok(ScoutObject {
+            object_name: field_str(record, "objectName").ok_or("scout object has no name")?,
+            rating: field_f64(record, "rating").map(|rating| rating as u8),
+            neo_score: field_f64(record, "neoScore"),
+            neo1km_score: field_f64(record, "neo1kmScore"),
+            pha_score: field_f64(record, "phaScore"),
+            ieo_score: field_f64(record, "ieoScore"),
+            geocentric_score: field_f64(record, "geocentricScore"),
+            h: field_f64(record, "H"),
+            vmag: field_f64(record, "Vmag"),
+            ra: field_str(record, "ra"),
+            dec: field_str(record, "dec"),
+            elong: field_f64(record, "elong"),
+            rate: field_f64(record, "rate"),
+            unc: field_f64(record, "unc"),
+            unc_p1: field_f64(record, "uncP1"),
+            n_obs: field_f64(record, "nObs").map(|n_obs| n_obs as u32),
+            arc: field_f64(record, "arc"),
+            rms_n: field_f64(record, "rmsN"),
+            moid: field_f64(record, "moid"),
+            ca_dist: field_f64(record, "caDist"),
+            v_inf: field_f64(record, "vInf"),
+            t_ephem: field_str(record, "tEphem"),
+            last_run: field_str(record, "lastRun"),
+        })
+    }
+}
+
+/// Orders objects for follow-up: highest rating first, then highest NEO score.
+/// Unrated objects come last.
+pub fn prioritize_scout(objects: &[ScoutObject]) -> Vec<&ScoutObject> {
+    let mut sorted: Vec<&ScoutObject> = objects.iter().collect();
+    sorted.sort_by(|a, b| {
+        b.rating.cmp(&a.rating).then_with(|| {
+            let a_score = a.neo_score.unwrap_or(0.0);
+            b.neo_score.unwrap_or(0.0).total_cmp(&a_score)
+        })
+    });
+    sorted
+}
+
+/// A single object with its sampled orbits and ephemeris, when they were requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutDetail {
+    /// Temporary designation
+    pub object_name: String,
+    /// Summary values of the object, when present in the response
+    pub summary: Option<ScoutObject>,
+    /// Sampled orbits, when `orbits` was requested
+    pub orbits: Vec<ScoutOrbit>,
+    /// Ephemeris, when `eph-start` was requested
+    pub ephemeris: Vec<ScoutEphemeris>,
+}
+
+impl ScoutDetail {
+    /// Parses an object response.
+    pub fn from_response(res: &Response) -> Result<ScoutDetail, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+
+        let object_name = field_str(&json, "objectName").ok_or("scout object has no name")?;
+        let summary = ScoutObject::from_record(&json).ok();
+        let orbits = match json.get("orbits") {
+            Some(orbits) => records(orbits)?
+                .iter()
+                .map(ScoutOrbit::from_record)
+                .collect::<Result<Vec<_>, _>>()?,
+            None => Vec::new(),
+        };
+        let ephemeris = json
+            .get("eph")
+            .and_then(JsonValue::as_array)
+            .map(|eph| eph.iter().map(ScoutEphemeris::from_json).collect())
+            .unwrap_or_default();
+
+        Ok(ScoutDetail {
+            object_name,
+            summary,
+            orbits,
+            ephemeris,
+        })
+    }
+}
+
+/// One of the orbits Scout samples from the object's uncertainty region.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutOrbit {
+    /// Index of the sampled orbit
+    pub idx: u32,
+    /// Epoch of the elements, Julian Date (TDB)
+    pub epoch: f64,
+    /// Eccentricity
+    pub ec: f64,
+    /// Perihelion distance (au)
+    pub qr: f64,
+    /// Time of perihelion passage, Julian Date (TDB)
+    pub tp: f64,
+    /// Longitude of the ascending node (deg)
+    pub om: f64,
+    /// Argument of perihelion (deg)
+    pub w: f64,
+    /// Inclination (deg)
+    pub inc: f64,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Earth MOID (au)
+    pub moid: Option<f64>,
+}
+
+impl ScoutOrbit {
+    fn from_record(record: &JsonValue) -> Result<ScoutOrbit, Box<dyn Error>> {
+        let required =
+            |name: &str| field_f64(record, name).ok_or(format!("scout orbit is missing {}", name));
+
+        Ok(ScoutOrbit {
+            idx: required("idx")? as u32,
+            epoch: required("epoch")?,
+            ec: required("ec")?,
+            qr: required("qr")?,
+            tp: required("tp")?,
+            om: required("om")?,
+            w: required("w")?,
+            inc: required("in")?,
+            h: field_f64(record, "H"),
+            moid: field_f64(record, "moid"),
+        })
+    }
+
+    /// Converts the sampled orbit into [`OrbitElements`] for use with [`crate::orbit`].
+    pub fn elements(&self) -> OrbitElements {
+        OrbitElements {
+            epoch: self.epoch,
+            e: self.ec,
+            a: if self.ec == 1.0 {
+                None
+            } else {
+                Some(self.qr / (1.0 - self.ec))
+            },
+            q: self.qr,
+            i: self.inc,
+            om: self.om,
+            w: self.w,
+            ma: None,
+            tp: Some(self.tp),
+        }
+    }
+}
+
+/// A single ephemeris point of an object, using the median of the sampled orbits.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoutEphemeris {
+    /// Time of the ephemeris point (UTC)
+    pub time: Option<String>,
+    /// Right ascension (deg)
+    pub ra: Option<f64>,
+    /// Declination (deg)
+    pub dec: Option<f64>,
+    /// Visual magnitude
+    pub vmag: Option<f64>,
+    /// Solar elongation (deg)
+    pub elong: Option<f64>,
+    /// Rate of motion (arcsec/min)
+    pub rate: Option<f64>,
+    /// Plane-of-sky position uncertainty (arcmin)
+    pub sigma_pos: Option<f64>,
+}
+
+impl ScoutEphemeris {
+    fn from_json(json: &JsonValue) -> ScoutEphemeris {
+        let values = json.get("median").unwrap_or(json);
+        ScoutEphemeris {
+            time: field_str(json, "time"),
+            ra: field_f64(values, "ra"),
+            dec: field_f64(values, "dec"),
+            vmag: field_f64(values, "vmag"),
+            elong: field_f64(values, "elong"),
+            rate: field_f64(values, "rate"),
+            sigma_pos: field_f64(json, "sigma-pos"),
+        }
+    }
+}
@@ -0,0 +1,382 @@
+use std::error::Error;
+
+use serde_json::Value as JsonValue;
+
+use super::{check_error, field_f64, field_str, records};
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the JPL Sentry impact monitoring API.
+///
+/// Supports summary mode (S), object mode (O) by designation or SPK-ID, virtual impactor mode (V)
+/// and removed objects mode (R). The ps-min, ip-min, h-max and days filters apply to the summary
+/// and virtual impactor modes.
+/// # Example
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let mut base = SentryClient::new();
+/// base.ps_min(-3.0);
+/// base.ip_min(1e-6);
+///
+/// let summaries = base.summaries().unwrap();
+/// let apophis = base.object(SentryTarget::Des(String::from("29075"))).unwrap();
+/// ```
+#[derive(Debug, PartialEq)]
+pub struct SentryClient {
+    base_url: String,
+    ps_min: Option<f64>,
+    ip_min: Option<f64>,
+    h_max: Option<f64>,
+    days: Option<i32>,
+}
+
+/// Selects a single object in Sentry's object mode.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SentryTarget {
+    /// Designation of the object, e.g. 29075 or 2000 SG344
+    Des(String),
+    /// SPK-ID of the object, e.g. 2029075
+    Spk(u32),
+}
+
+impl SentryClient {
+    pub fn new() -> Self {
+        SentryClient {
+            base_url: String::from("https://ssd-api.jpl.nasa.gov/sentry.api"),
+            ps_min: None,
+            ip_min: None,
+            h_max: None,
+            days: None,
+        }
+    }
+
+    /// Exclude objects with a Palermo scale value below this value
+    pub fn ps_min(&mut self, ps_min: f64) {
+        self.ps_min = Some(ps_min)
+    }
+
+    /// Exclude objects with an impact probability below this value
+    pub fn ip_min(&mut self, ip_min: f64) {
+        self.ip_min = Some(ip_min)
+    }
+
+    /// Exclude objects with an absolute magnitude greater (fainter) than this value
+    pub fn h_max(&mut self, h_max: f64) {
+        self.h_max = Some(h_max)
+    }
+
+    /// Exclude objects last observed more than this many days ago (negative values
+    /// exclude objects observed within that many days)
+    pub fn days(&mut self, days: i32) {
+        self.days = Some(days)
+    }
+
+    fn filters(&self, url: &mut UrlBuilder) -> Result<(), Box<dyn Error>> {
+        if let Some(ip_min) = self.ip_min {
+            if !(ip_min > 0.0 && ip_min <= 1.0) {
+                return Err(format!("ip-min {} is not a probability", ip_min).into());
+            }
+        }
+
+        if let Some(ps_min) = self.ps_min {
+            url.param("ps-min", ps_min);
+        }
+        if let Some(ip_min) = self.ip_min {
+            url.param("ip-min", ip_min);
+        }
+        if let Some(h_max) = self.h_max {
+            url.param("h-max", h_max);
+        }
+        if let Some(days) = self.days {
+            url.param("days", days);
+        }
+        Ok(())
+    }
+
+    /// Summary mode url, including the filters
+    pub fn summary_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+        self.filters(&mut url)?;
+        Ok(url.build())
+    }
+
+    /// Object mode url for a single object
+    pub fn object_url(&self, target: &SentryTarget) -> String {
+        let mut url = UrlBuilder::new(&self.base_url);
+        match target {
+            SentryTarget::Des(des) => url.param("des", des),
+            SentryTarget::Spk(spk) => url.param("spk", spk),
+        };
+        url.build()
+    }
+
+    /// Virtual impactor mode url, including the filters
+    pub fn virtual_impactors_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("all", 1);
+        self.filters(&mut url)?;
+        Ok(url.build())
+    }
+
+    /// Removed objects mode url
+    pub fn removed_url(&self) -> String {
+        UrlBuilder::new(&self.base_url).param("removed", 1).build()
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let sentry = into_response(res.as_str())?;
+        check_error(&sentry.json()?)?;
+
+        Ok(sentry)
+    }
+
+    /// Sentry: S mode (summary of every object)
+    pub fn summary(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.summary_url()?)
+    }
+
+    /// Sentry: O mode (a single object and its virtual impactors)
+    pub fn query(&self, target: &SentryTarget) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.object_url(target))
+    }
+
+    /// Sentry: V mode (virtual impactors of every object)
+    pub fn virtual_impactors(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.virtual_impactors_url()?)
+    }
+
+    /// Sentry: R mode (objects removed from the risk list)
+    pub fn removed(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.removed_url())
+    }
+
+    /// Summary mode parsed into [`SentrySummary`] rows
+    pub fn summaries(&self) -> Result<Vec<SentrySummary>, Box<dyn Error>> {
+        SentrySummary::from_response(&self.summary()?)
+    }
+
+    /// Object mode parsed into a [`SentryObject`]
+    pub fn object(&self, target: SentryTarget) -> Result<SentryObject, Box<dyn Error>> {
+        SentryObject::from_response(&self.query(&target)?)
+    }
+
+    /// Removed objects mode parsed into [`SentryRemoved`] rows
+    pub fn removed_objects(&self) -> Result<Vec<SentryRemoved>, Box<dyn Error>> {
+        SentryRemoved::from_response(&self.removed()?)
+    }
+}
+
+impl Default for SentryClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One object of the Sentry summary table.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentrySummary {
+    /// Object designation
+    pub des: String,
+    /// Full name of the object
+    pub fullname: Option<String>,
+    /// Cumulative impact probability
+    pub ip: f64,
+    /// Cumulative Palermo scale value
+    pub ps_cum: f64,
+    /// Maximum Palermo scale value
+    pub ps_max: f64,
+    /// Maximum Torino scale value, absent when not computed
+    pub ts_max: Option<u8>,
+    /// Number of potential impacts
+    pub n_imp: u32,
+    /// Range of years with potential impacts, e.g. "2880-2880"
+    pub range: Option<String>,
+    /// Date of the last observation used
+    pub last_obs: Option<String>,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Estimated diameter, in kilometers
+    pub diameter: Option<f64>,
+    /// Velocity at atmospheric entry, in km/s
+    pub v_inf: Option<f64>,
+}
+
+impl SentrySummary {
+    /// Parses every row of a summary mode response.
+    pub fn from_response(res: &Response) -> Result<Vec<SentrySummary>, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        records(&json)?
+            .iter()
+            .map(|record| {
+                Ok(SentrySummary {
+                    des: field_str(record, "des").ok_or("sentry object is missing its des")?,
+                    fullname: field_str(record, "fullname"),
+                    ip: field_f64(record, "ip").ok_or("sentry object is missing its ip")?,
+                    ps_cum: field_f64(record, "ps_cum")
+                        .ok_or("sentry object is missing its ps_cum")?,
+                    ps_max: field_f64(record, "ps_max")
+                        .ok_or("sentry object is missing its ps_max")?,
+                    ts_max: torino(record, "ts_max"),
+                    n_imp: field_f64(record, "n_imp").ok_or("sentry object is missing its n_imp")?
+                        as u32,
+                    range: field_str(record, "range"),
+                    last_obs: field_str(record, "last_obs"),
+                    h: field_f64(record, "h"),
+                    diameter: field_f64(record, "diameter"),
+                    v_inf: field_f64(record, "v_inf"),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A single object in Sentry's object mode, with its table of virtual impactors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentryObject {
+    /// Object designation
+    pub des: String,
+    /// Full name of the object
+    pub fullname: Option<String>,
+    /// Cumulative impact probability
+    pub ip: f64,
+    /// Cumulative Palermo scale value
+    pub ps_cum: f64,
+    /// Maximum Palermo scale value
+    pub ps_max: f64,
+    /// Maximum Torino scale value
+    pub ts_max: Option<u8>,
+    /// Number of potential impacts
+    pub n_imp: u32,
+    /// Absolute magnitude
+    pub h: Option<f64>,
+    /// Estimated diameter, in kilometers
+    pub diameter: Option<f64>,
+    /// Estimated mass, in kilograms
+    pub mass: Option<f64>,
+    /// Estimated impact energy, in megatons
+    pub energy: Option<f64>,
+    /// Velocity at atmospheric entry, in km/s
+    pub v_inf: Option<f64>,
+    /// Velocity relative to a massless Earth, in km/s
+    pub v_imp: Option<f64>,
+    /// Date of the first observation used
+    pub first_obs: Option<String>,
+    /// Date of the last observation used
+    pub last_obs: Option<String>,
+    /// Number of observations used
+    pub nobs: Option<u32>,
+    /// Analysis method used (IOBS, LOV or MC)
+    pub method: Option<String>,
+    /// Potential impacts, one per virtual impactor
+    pub virtual_impactors: Vec<VirtualImpactor>,
+}
+
+impl SentryObject {
+    /// Parses an object mode response.
+    pub fn from_response(res: &Response) -> Result<SentryObject, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        let summary = json
+            .get("summary")
+            .ok_or("sentry response has no summary")?;
+
+        let virtual_impactors = records(&json)?
+            .iter()
+            .map(VirtualImpactor::from_record)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SentryObject {
+            des: field_str(summary, "des").ok_or("sentry object is missing its des")?,
+            fullname: field_str(summary, "fullname"),
+            ip: field_f64(summary, "ip").ok_or("sentry object is missing its ip")?,
+            ps_cum: field_f64(summary, "ps_cum").ok_or("sentry object is missing its ps_cum")?,
+            ps_max: field_f64(summary, "ps_max").ok_or("sentry object is missing its ps_max")?,
+            ts_max: torino(summary, "ts_max"),
+            n_imp: field_f64(summary, "n_imp").ok_or("sentry object is missing its n_imp")? as u32,
+            h: field_f64(summary, "h"),
+            diameter: field_f64(summary, "diameter"),
+            mass: field_f64(summary, "mass"),
+            energy: field_f64(summary, "energy"),
+            v_inf: field_f64(summary, "v_inf"),
+            v_imp: field_f64(summary, "v_imp"),
+            first_obs: field_str(summary, "first_obs"),
+            last_obs: field_str(summary, "last_obs"),
+            nobs: field_f64(summary, "nobs").map(|nobs| nobs as u32),
+            method: field_str(summary, "method"),
+            virtual_impactors,
+        })
+    }
+}
+
+/// A potential impact (virtual impactor) of a Sentry object.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualImpactor {
+    /// Date of the potential impact (TDB), e.g. "2880-03-16.84"
+    pub date: String,
+    /// Impact probability
+    pub ip: f64,
+    /// Palermo scale value
+    pub ps: f64,
+    /// Torino scale value
+    pub ts: Option<u8>,
+    /// Impact energy, in megatons
+    pub energy: Option<f64>,
+    /// Minimum distance to Earth's center, in Earth radii
+    pub dist: Option<f64>,
+    /// One-sigma semi-width of the LOV uncertainty region, in Earth radii
+    pub width: Option<f64>,
+    /// Approximate sigma of the virtual impactor along the line of variations
+    pub sigma_lov: Option<f64>,
+    /// Approximate sigma from the nominal orbit
+    pub sigma_imp: Option<f64>,
+}
+
+impl VirtualImpactor {
+    fn from_record(record: &JsonValue) -> Result<VirtualImpactor, Box<dyn Error>> {
+        Ok(VirtualImpactor {
+            date: field_str(record, "date").ok_or("virtual impactor is missing its date")?,
+            ip: field_f64(record, "ip").ok_or("virtual impactor is missing its ip")?,
+            ps: field_f64(record, "ps").ok_or("virtual impactor is missing its ps")?,
+            ts: torino(record, "ts"),
+            energy: field_f64(record, "energy"),
+            dist: field_f64(record, "dist"),
+            width: field_f64(record, "width"),
+            sigma_lov: field_f64(record, "sigma_lov"),
+            sigma_imp: field_f64(record, "sigma_imp"),
+        })
+    }
+}
+
+/// An object that has been removed from the Sentry risk list.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentryRemoved {
+    /// Object designation
+    pub des: String,
+    /// Date and time the object was removed
+    pub removed: String,
+}
+
+impl SentryRemoved {
+    /// Parses every row of a removed objects mode response.
+    pub fn from_response(res: &Response) -> Result<Vec<SentryRemoved>, Box<dyn Error>> {
+        let json = res.json()?;
+        check_error(&json)?;
+        records(&json)?
+            .iter()
+            .map(|record| {
+                Ok(SentryRemoved {
+                    des: field_str(record, "des").ok_or("removed object is missing its des")?,
+                    removed: field_str(record, "removed")
+                        .ok_or("removed object is missing its date")?,
+                })
+            })
+            .collect()
+    }
+}
+
+fn torino(record: &JsonValue, key: &str) -> Option<u8> {
+    field_str(record, key)?.trim().parse().ok()
+}
@@ -4,9 +4,18 @@
 //! Features light bindings for a multitude of APIs. Including:
 //! * Picture of the day -> [`apod`]
 //! * Database of Notifications, Knowledge, Information -> [`donki`]  
+//! * Earth (Landsat imagery) -> [`earth`]
+//! * Earth Observatory Natural Event Tracker -> [`eonet`]
+//! * Earth Polychromatic Imaging Camera -> [`epic`]
+//! * Exoplanet Archive -> [`exoplanet`]
+//! * Image and Video Library -> [`images`]
 //! * Jet Propulsion Laboratory -> [`jpl`]
+//! * Mars Rover Photos -> [`mars_photos`]
 //! * Near Earth Objects -> [`neo`]
+//! * Open Science Data Repository (GeneLab) -> [`osdr`]
 //! * Tech Transfer -> [`tech`]
+//! * TechPort -> [`techport`]
+//! * Two-line element sets -> [`tle`]
 
 //! # Features
 //! * Base clients for interacting with NASA's open APIs
@@ -146,6 +155,17 @@ pub mod neo;
 /// // Query Endpoint
 /// let res = base.query().unwrap();
 /// ```
+///
+/// # Typed sol series
+/// ```no_run
+/// use voyager_client::insight;
+///
+/// let series = insight::InsightWeather::new().weather().unwrap();
+/// for sol in &series.sols {
+///     println!("sol {}: {:?}", sol.sol, sol.at.as_ref().map(|at| at.av));
+/// }
+/// let csv = series.to_csv();
+/// ```
 pub mod insight;
 
 
@@ -184,8 +204,196 @@ pub mod response;
 /// let query = String::from("engine");
 /// base.query(query).unwrap();
 /// ```
+///
+/// # Typed results
+/// ```no_run
+/// use voyager_client::tech::*;
+///
+/// let mut base = TechTransferClient::new();
+/// base.switch(Collections::Spinoff).unwrap();
+/// base.page(1);
+///
+/// let page = base.search("engine").unwrap();
+/// println!("{} of {} spinoffs", page.count, page.total);
+/// ```
 pub mod tech;
 
+/// For interacting with the EONET v3 natural events API. No api key is needed.
+///
+/// # Open wildfires of the last week
+/// ```no_run
+/// use voyager_client::eonet::*;
+///
+/// let mut base = EonetClient::new();
+/// base.category("wildfires");
+/// base.status(EventStatus::Open);
+/// base.days(7);
+///
+/// for event in base.events().unwrap() {
+///     println!("{}: {:?}", event.title, event.latest().map(|geometry| &geometry.shape));
+/// }
+///
+/// // The same events as a GeoJSON FeatureCollection
+/// let geojson = base.events_geojson().unwrap();
+/// ```
+pub mod eonet;
+
+/// For interacting with the EPIC API, full disc images of the Earth taken from DSCOVR.
+///
+/// # Download the latest enhanced thumbnails
+/// ```no_run
+/// use voyager_client::epic::*;
+///
+/// let mut base = EpicClient::new();
+/// base.collection(EpicCollection::Enhanced);
+///
+/// for image in base.latest().unwrap() {
+///     println!("{}", base.image_url(&image, ImageFormat::Png).unwrap());
+///     let jpg: Vec<u8> = base.download(&image, ImageFormat::Thumbs).unwrap();
+/// }
+/// ```
+pub mod epic;
+
+/// For interacting with the Mars Rover Photos API.
+///
+/// # Navigation camera photos of Curiosity's sol 1000
+/// ```no_run
+/// use voyager_client::mars_photos::*;
+///
+/// let mut base = MarsPhotosClient::new(MarsRover::Curiosity);
+/// base.sol(1000);
+/// base.camera("NAVCAM");
+///
+/// for photo in base.photos().unwrap() {
+///     println!("{} {}", photo.camera.full_name, photo.img_src);
+/// }
+///
+/// // Sols when the front hazard camera took photos
+/// let sols = base.manifest().unwrap().sols_with_camera("FHAZ");
+/// ```
+pub mod mars_photos;
+
+/// For interacting with the NASA Image and Video Library API. No api key is needed.
+///
+/// # Original Apollo 11 images
+/// ```no_run
+/// use voyager_client::images::*;
+///
+/// let mut base = ImageLibraryClient::new();
+/// base.q("apollo 11");
+/// base.media_types(&[MediaType::Image]);
+/// base.year_start(1969);
+/// base.year_end(1969);
+///
+/// let results = base.search().unwrap();
+/// for item in results.items {
+///     let nasa_id = &item.info().unwrap().nasa_id;
+///     println!("{:?}", base.asset(nasa_id).unwrap().rendition(Rendition::Original));
+/// }
+/// ```
+pub mod images;
+
+/// For querying the NASA Exoplanet Archive with ADQL. No api key is needed.
+///
+/// # Planets of Kepler-11, and a query of your own
+/// ```no_run
+/// use voyager_client::exoplanet::*;
+///
+/// let base = ExoplanetClient::new();
+///
+/// for planet in base.host_planets("Kepler-11").unwrap() {
+///     println!("{} {:?} days", planet.pl_name, planet.pl_orbper);
+/// }
+///
+/// let table = base
+///     .table("select hostname, count(*) as planets from pscomppars group by hostname")
+///     .unwrap();
+/// println!("{:?} {:?}", table.get(0, "hostname"), table.get_f64(0, "planets"));
+/// ```
+pub mod exoplanet;
+
+/// For interacting with the TechPort API, NASA's technology projects.
+///
+/// # Projects updated this year
+/// ```no_run
+/// use voyager_client::techport::*;
+///
+/// let base = TechPortClient::new();
+///
+/// for summary in base.updated_since("2024-01-01").unwrap() {
+///     let project = base.project(summary.project_id).unwrap();
+///     println!(
+///         "{} ({:?}): TRL {:?} -> {:?}",
+///         project.title, project.status, project.trl_begin, project.trl_end
+///     );
+/// }
+/// ```
+pub mod techport;
+
+/// For interacting with the TLE API, and propagating two-line element sets with SGP4.
+/// No api key is needed.
+///
+/// # Ground track of the ISS over one orbit
+/// ```no_run
+/// use chrono::Duration;
+/// use voyager_client::tle::*;
+///
+/// let iss = TleClient::new().fetch(25544).unwrap().tle().unwrap();
+/// let sgp4 = iss.sgp4().unwrap();
+///
+/// let start = sgp4.epoch();
+/// let end = start + Duration::minutes(iss.period() as i64);
+/// for point in sgp4.ground_track(start, end, Duration::minutes(1)).unwrap() {
+///     println!("{} {:.3} {:.3}", point.datetime, point.latitude, point.longitude);
+/// }
+/// ```
+///
+/// Cached element sets parse the same way, with [`tle::Tle::parse_lines`].
+pub mod tle;
+
+/// For interacting with the Earth API, Landsat 8 imagery of a location.
+///
+/// # Scene and image of a date
+/// ```no_run
+/// use voyager_client::earth::*;
+///
+/// let mut base = EarthClient::new();
+/// base.location(1.5, 100.75);
+/// base.date("2014-02-01");
+/// base.dim(0.15);
+///
+/// let assets = base.assets().unwrap();
+/// let png: Vec<u8> = base.image().unwrap();
+/// ```
+pub mod earth;
+
+/// For searching the Open Science Data Repository (GeneLab) and listing study files.
+/// No api key is needed.
+///
+/// # Mouse spaceflight studies and their files
+/// ```no_run
+/// use voyager_client::osdr::*;
+///
+/// let mut base = OsdrClient::new();
+/// base.term("spaceflight");
+/// base.sources(&[DataSource::GeneLab]);
+/// base.filter("organism", "Mus musculus");
+/// base.size(25);
+///
+/// let results = base.search().unwrap();
+/// println!("{} datasets", results.total);
+/// for dataset in results.datasets {
+///     println!("{:?}: {:?}", dataset.accession, dataset.title);
+/// }
+///
+/// for file in base.study_files(87).unwrap() {
+///     println!("{} {:?} {}", file.file_name, file.file_size, file.url());
+/// }
+/// ```
+pub mod osdr;
+
+mod url_builder;
+
 
 /// Jet Propulsion Laboratory
 /// # Example usage with FireballClient
@@ -200,7 +408,22 @@ pub mod tech;
 ///
 /// base.query().unwrap();
 /// ```
-/// 
+///
+/// # Typed fireball records
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let base = FireballClient::new();
+/// let fireballs = base.fireballs().unwrap();
+///
+/// // Total impact energy (kt) per year, and the ten strongest events
+/// let per_year = fireball_energy_by_year(&fireballs);
+/// let strongest = strongest_fireballs(&fireballs, 10);
+///
+/// // GeoJSON FeatureCollection of every located event
+/// let geojson = fireballs_to_geojson(&fireballs);
+/// ```
+///
 /// # Example usage with MissionDesign
 /// ```
 /// use voyager_client::jpl::*;
@@ -208,12 +431,43 @@ pub mod tech;
 /// // Instantiate Base Client
 /// let mut base = MissionDesign::new();
 /// 
-/// base.query(QueryType::DES, "2012%20TC4").unwrap();
+/// base.query(QueryType::DES, "2012 TC4").unwrap();
+/// ```
+///
+/// # Typed mission design trajectories
+/// ```no_run
+/// use voyager_client::jpl::*;
+///
+/// let result = MissionDesign::new().missions(QueryType::DES, "2012 TC4").unwrap();
+/// let cheapest = result.lowest_delta_v();
+///
+/// let mut base = MissionDesignAccessible::new();
+/// base.limit(10);
+/// base.crit(1);
+/// base.year(String::from("2025,2026"));
+/// base.rdvz(true);
+/// let targets = base.targets().unwrap();
 /// ```
 /// 
 pub mod jpl;
 
 
+/// Two-body propagation of heliocentric orbits, for elements from [`jpl::SbdbClient`] or the
+/// `orbital_data` of a NEO lookup. Elliptic, parabolic and hyperbolic orbits are supported.
+///
+/// # Earth distance of Eros over one year
+/// ```no_run
+/// use voyager_client::{jpl, orbit};
+///
+/// let eros = jpl::SbdbClient::new().object(jpl::QueryType::DES, "433").unwrap();
+///
+/// let jds = orbit::jd_range(2459600.5, 2459965.5, 5.0).unwrap();
+/// let states = orbit::states(&eros.orbit, &jds).unwrap();
+/// let distances = orbit::earth_distances(&eros.orbit, &jds).unwrap();
+/// ```
+pub mod orbit;
+
+
 /// For handling different request times. Known overflow errors at the moment, so use with caution. Use manual dates if possible.
 ///
 /// # Query in a one month range
@@ -230,6 +484,17 @@ pub mod jpl;
 /// let start_date = time::one_week();
 /// let end_date = time::today();
 /// ```
+/// # Julian Dates and JPL calendar strings
+/// ```
+/// use voyager_client::time;
+///
+/// let date = time::parse_jpl_date("2019-Jan-07 00:00").unwrap();
+/// assert_eq!(time::modified_julian_date(date), 58490.0);
+/// assert_eq!(time::julian_date(date), 58490.0 + time::MJD_OFFSET);
+///
+/// let tdb = time::utc_to_tdb(date);
+/// assert_eq!(time::tdb_to_utc(tdb), date);
+/// ```
 pub mod time;
 
 
@@ -0,0 +1,257 @@
+use std::collections::BTreeMap;
+use std::error::Error;
+
+use chrono::NaiveDate;
+use serde_derive::Deserialize;
+
+use crate::key;
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the Mars Rover Photos API.
+///
+/// Photos are selected by either a sol or an Earth date, and optionally a camera and page
+/// (25 photos per page).
+#[derive(Debug, PartialEq)]
+pub struct MarsPhotosClient {
+    base_url: String,
+    rover: MarsRover,
+    sol: Option<u32>,
+    earth_date: Option<String>,
+    camera: Option<String>,
+    page: Option<u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarsRover {
+    Curiosity,
+    Opportunity,
+    Spirit,
+    Perseverance,
+}
+
+impl MarsRover {
+    fn as_str(&self) -> &'static str {
+        match self {
+            MarsRover::Curiosity => "curiosity",
+            MarsRover::Opportunity => "opportunity",
+            MarsRover::Spirit => "spirit",
+            MarsRover::Perseverance => "perseverance",
+        }
+    }
+}
+
+impl MarsPhotosClient {
+    /// Create a new base client for the given rover
+    pub fn new(rover: MarsRover) -> Self {
+        MarsPhotosClient {
+            base_url: String::from("https://api.nasa.gov/mars-photos/api/v1"),
+            rover,
+            sol: None,
+            earth_date: None,
+            camera: None,
+            page: None,
+        }
+    }
+
+    pub fn rover(&mut self, rover: MarsRover) {
+        self.rover = rover
+    }
+
+    /// Martian sol of the photos, counted from landing
+    pub fn sol(&mut self, sol: u32) {
+        self.sol = Some(sol)
+    }
+
+    /// Earth date of the photos, formatted YYYY-MM-DD
+    pub fn earth_date(&mut self, earth_date: &str) {
+        self.earth_date = Some(String::from(earth_date))
+    }
+
+    /// Camera abbreviation, e.g. FHAZ, NAVCAM or MAST
+    pub fn camera(&mut self, camera: &str) {
+        self.camera = Some(camera.to_lowercase())
+    }
+
+    /// Page of results, starting at 1
+    pub fn page(&mut self, page: u32) {
+        self.page = Some(page)
+    }
+
+    fn rover_url(&self, path: &str) -> String {
+        format!("{}/rovers/{}{}", self.base_url, self.rover.as_str(), path)
+    }
+
+    fn photos_builder(&self) -> Result<UrlBuilder, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.rover_url("/photos"));
+        match (self.sol, &self.earth_date) {
+            (Some(_), Some(_)) => return Err("set either a sol or an earth date, not both".into()),
+            (None, None) => return Err("photos require a sol or an earth date".into()),
+            (Some(sol), None) => {
+                url.param("sol", sol);
+            }
+            (None, Some(earth_date)) => {
+                NaiveDate::parse_from_str(earth_date, "%Y-%m-%d")
+                    .map_err(|_| format!("{} is not a YYYY-MM-DD date", earth_date))?;
+                url.param("earth_date", earth_date);
+            }
+        }
+        if let Some(camera) = &self.camera {
+            url.param("camera", camera);
+        }
+        match self.page {
+            Some(0) => return Err("pages start at 1".into()),
+            Some(page) => {
+                url.param("page", page);
+            }
+            None => {}
+        }
+        Ok(url)
+    }
+
+    /// Photos url, without the api key
+    pub fn photos_url(&self) -> Result<String, Box<dyn Error>> {
+        Ok(self.photos_builder()?.build())
+    }
+
+    fn get(&self, mut url: UrlBuilder) -> Result<Response, Box<dyn Error>> {
+        let key: String = key::from_dotenv()?;
+        url.param("api_key", key);
+
+        let res: String = ureq::get(&url.build()).call()?.into_string()?;
+        let photos = into_response(res.as_str())?;
+
+        Ok(photos)
+    }
+
+    /// Mars Photos: photos of the sol or Earth date
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(self.photos_builder()?)
+    }
+
+    /// Photos of the sol or Earth date, parsed into [`Photo`] records
+    pub fn photos(&self) -> Result<Vec<Photo>, Box<dyn Error>> {
+        Photo::from_response(&self.query()?, "photos")
+    }
+
+    /// Photos of the most recent sol with photos
+    pub fn latest_photos(&self) -> Result<Vec<Photo>, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.rover_url("/latest_photos"));
+        if let Some(camera) = &self.camera {
+            url.param("camera", camera);
+        }
+        Photo::from_response(&self.get(url)?, "latest_photos")
+    }
+
+    /// Every rover, with its cameras
+    pub fn rovers(&self) -> Result<Vec<Rover>, Box<dyn Error>> {
+        let res = self.get(UrlBuilder::new(&format!("{}/rovers", self.base_url)))?;
+        res.field("rovers")
+    }
+
+    /// Mission details of the selected rover
+    pub fn rover_info(&self) -> Result<Rover, Box<dyn Error>> {
+        self.get(UrlBuilder::new(&self.rover_url("")))?
+            .field("rover")
+    }
+
+    /// Mission manifest of the selected rover, listing the photos taken each sol
+    pub fn manifest(&self) -> Result<Manifest, Box<dyn Error>> {
+        let url = format!("{}/manifests/{}", self.base_url, self.rover.as_str());
+        Manifest::from_response(&self.get(UrlBuilder::new(&url))?)
+    }
+}
+
+/// A photo taken by a rover.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Photo {
+    pub id: u64,
+    pub sol: u32,
+    pub camera: Camera,
+    /// Url of the full size image
+    pub img_src: String,
+    /// Earth date the photo was taken, YYYY-MM-DD
+    pub earth_date: String,
+    pub rover: Rover,
+}
+
+impl Photo {
+    /// Parses the photos of a response, stored under `key` (photos or latest_photos).
+    pub fn from_response(res: &Response, key: &str) -> Result<Vec<Photo>, Box<dyn Error>> {
+        res.field(key)
+    }
+}
+
+/// A rover camera.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Camera {
+    pub id: Option<u32>,
+    /// Abbreviation, e.g. FHAZ
+    pub name: String,
+    pub rover_id: Option<u32>,
+    /// Full name, e.g. Front Hazard Avoidance Camera
+    pub full_name: String,
+}
+
+/// A rover and its mission.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Rover {
+    pub id: u32,
+    pub name: String,
+    pub landing_date: String,
+    pub launch_date: String,
+    /// Mission status, e.g. active or complete
+    pub status: String,
+    pub max_sol: Option<u32>,
+    pub max_date: Option<String>,
+    pub total_photos: Option<u64>,
+    #[serde(default)]
+    pub cameras: Vec<Camera>,
+}
+
+/// The photos a rover took over its mission, sol by sol.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Manifest {
+    pub name: String,
+    pub landing_date: String,
+    pub launch_date: String,
+    pub status: String,
+    pub max_sol: u32,
+    pub max_date: String,
+    pub total_photos: u64,
+    pub photos: Vec<ManifestSol>,
+}
+
+/// The photos of one sol in a [`Manifest`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ManifestSol {
+    pub sol: u32,
+    pub earth_date: String,
+    pub total_photos: u64,
+    /// Abbreviations of the cameras that took photos
+    pub cameras: Vec<String>,
+}
+
+impl Manifest {
+    /// Parses a manifest response.
+    pub fn from_response(res: &Response) -> Result<Manifest, Box<dyn Error>> {
+        res.field("photo_manifest")
+    }
+
+    /// Cameras that took photos, by sol
+    pub fn cameras_by_sol(&self) -> BTreeMap<u32, Vec<String>> {
+        self.photos
+            .iter()
+            .map(|sol| (sol.sol, sol.cameras.clone()))
+            .collect()
+    }
+
+    /// Sols with photos from a camera, e.g. NAVCAM
+    pub fn sols_with_camera(&self, camera: &str) -> Vec<u32> {
+        self.photos
+            .iter()
+            .filter(|sol| sol.cameras.iter().any(|c| c.eq_ignore_ascii_case(camera)))
+            .map(|sol| sol.sol)
+            .collect()
+    }
+}
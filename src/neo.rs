@@ -2,6 +2,7 @@ use std::error::Error;
 
 use crate::key;
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
 
 /// Base client for the Near Earth Objects API. Contains two methods, new, and query.
 #[derive(Debug)]
@@ -13,14 +14,18 @@ impl Neo {
 
     pub fn new() -> Self {
         Neo {
-            base_url: String::from("https://api.nasa.gov/neo/rest/v1/feed?start_date="),
+            base_url: String::from("https://api.nasa.gov/neo/rest/v1/feed"),
         }
     }
 
     pub fn query(&self, start: String, end: String) -> Result<Response, Box<dyn Error>> {
         let key: String = key::from_dotenv()?;
 
-        let url: String = format!("{}{}&endDate={}&api_key={}", self.base_url, start, end, key);
+        let url: String = UrlBuilder::new(&self.base_url)
+            .param("start_date", &start)
+            .param("endDate", &end)
+            .param("api_key", key)
+            .build();
         println!("Starting Neo query from {}, to {}.", start, end);
 
         let res: String = ureq::get(&url).call()?.into_string()?;
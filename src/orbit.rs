@@ -0,0 +1,269 @@
+use std::error::Error;
+use std::f64::consts::PI;
+
+use serde_json::Value as JsonValue;
+
+use crate::jpl::{field_f64, OrbitElements};
+
+/// Gaussian gravitational constant, in au^(3/2) / day
+pub const GAUSS_K: f64 = 0.01720209895;
+
+/// Heliocentric gravitational parameter, in au^3 / day^2
+pub const GM_SUN: f64 = GAUSS_K * GAUSS_K;
+
+/// Julian Date of the J2000 epoch (2000-01-01 12:00 TDB)
+pub const J2000: f64 = 2451545.0;
+
+/// Eccentricities this close to 1 are propagated as parabolic orbits.
+const PARABOLIC_TOLERANCE: f64 = 1e-9;
+
+/// Convergence tolerance of the Kepler equation solvers, in radians
+const KEPLER_TOLERANCE: f64 = 1e-14;
+
+/// Most dates [`jd_range`] will generate
+pub const MAX_RANGE_POINTS: usize = 1_000_000;
+
+/// Heliocentric position (au) and velocity (au/day) in the ecliptic J2000 frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    /// Julian Date (TDB)
+    pub jd: f64,
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+}
+
+impl StateVector {
+    /// Distance from the Sun, in au
+    pub fn distance(&self) -> f64 {
+        norm(self.position)
+    }
+
+    /// Distance to another state vector, in au
+    pub fn distance_to(&self, other: &StateVector) -> f64 {
+        norm([
+            self.position[0] - other.position[0],
+            self.position[1] - other.position[1],
+            self.position[2] - other.position[2],
+        ])
+    }
+}
+
+/// Solves Kepler's equation `M = E - e sin E` for the eccentric anomaly of an elliptic orbit.
+/// Angles are in radians.
+pub fn solve_kepler(mean_anomaly: f64, e: f64) -> f64 {
+    let m = mean_anomaly.rem_euclid(2.0 * PI);
+    let mut big_e = if e < 0.8 { m } else { PI };
+    for _ in 0..100 {
+        let delta = (big_e - e * big_e.sin() - m) / (1.0 - e * big_e.cos());
+        big_e -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+    big_e + (mean_anomaly - m)
+}
+
+/// Solves the hyperbolic Kepler equation `M = e sinh H - H` for the hyperbolic anomaly.
+/// Angles are in radians.
+pub fn solve_hyperbolic_kepler(mean_anomaly: f64, e: f64) -> f64 {
+    let mut h = (mean_anomaly / e).asinh();
+    for _ in 0..100 {
+        let delta = (e * h.sinh() - h - mean_anomaly) / (e * h.cosh() - 1.0);
+        h -= delta;
+        if delta.abs() < KEPLER_TOLERANCE {
+            break;
+        }
+    }
+    h
+}
+
+/// Solves Barker's equation `M = D + D^3 / 3` for `D = tan(v / 2)` of a parabolic orbit.
+pub fn solve_barker(mean_anomaly: f64) -> f64 {
+    let a = 1.5 * mean_anomaly;
+    let b = (a + (a * a + 1.0).sqrt()).cbrt();
+    b - 1.0 / b
+}
+
+/// Propagates the elements to the given Julian Date (TDB) with two-body motion around the Sun.
+pub fn state_at(elements: &OrbitElements, jd: f64) -> Result<StateVector, Box<dyn Error>> {
+    let e = elements.e;
+    let q = elements.q;
+    if e < 0.0 || q <= 0.0 {
+        return Err(format!("invalid orbit with e = {} and q = {}", e, q).into());
+    }
+
+    let (perifocal_position, perifocal_velocity) = if (e - 1.0).abs() < PARABOLIC_TOLERANCE {
+        let tp = elements
+            .tp
+            .ok_or("parabolic orbits need a time of perihelion")?;
+        let mean_anomaly = (GM_SUN / (2.0 * q.powi(3))).sqrt() * (jd - tp);
+        let d = solve_barker(mean_anomaly);
+
+        let p = 2.0 * q;
+        let cos_v = (1.0 - d * d) / (1.0 + d * d);
+        let sin_v = 2.0 * d / (1.0 + d * d);
+        let speed = (GM_SUN / p).sqrt();
+        (
+            [q * (1.0 - d * d), 2.0 * q * d],
+            [-speed * sin_v, speed * (1.0 + cos_v)],
+        )
+    } else if e < 1.0 {
+        let a = q / (1.0 - e);
+        let n = GAUSS_K / a.powf(1.5);
+        let big_e = solve_kepler(mean_anomaly(elements, n, jd)?, e);
+
+        let b = a * (1.0 - e * e).sqrt();
+        let r = a * (1.0 - e * big_e.cos());
+        let scale = (GM_SUN * a).sqrt() / r;
+        (
+            [a * (big_e.cos() - e), b * big_e.sin()],
+            [
+                -scale * big_e.sin(),
+                scale * (1.0 - e * e).sqrt() * big_e.cos(),
+            ],
+        )
+    } else {
+        let a = q / (e - 1.0);
+        let n = GAUSS_K / a.powf(1.5);
+        let h = solve_hyperbolic_kepler(mean_anomaly(elements, n, jd)?, e);
+
+        let b = a * (e * e - 1.0).sqrt();
+        let r = a * (e * h.cosh() - 1.0);
+        let scale = (GM_SUN * a).sqrt() / r;
+        (
+            [a * (e - h.cosh()), b * h.sinh()],
+            [-scale * h.sinh(), scale * (e * e - 1.0).sqrt() * h.cosh()],
+        )
+    };
+
+    let rotation = perifocal_to_ecliptic(elements);
+    Ok(StateVector {
+        jd,
+        position: rotate(&rotation, perifocal_position),
+        velocity: rotate(&rotation, perifocal_velocity),
+    })
+}
+
+/// Propagates the elements to each of the given Julian Dates.
+pub fn states(elements: &OrbitElements, jds: &[f64]) -> Result<Vec<StateVector>, Box<dyn Error>> {
+    jds.iter().map(|jd| state_at(elements, *jd)).collect()
+}
+
+/// Distance between the object and the Earth-Moon barycenter (au) at each Julian Date,
+/// using [`earth_elements`] for the Earth.
+pub fn earth_distances(
+    elements: &OrbitElements,
+    jds: &[f64],
+) -> Result<Vec<(f64, f64)>, Box<dyn Error>> {
+    let earth = earth_elements();
+    jds.iter()
+        .map(|jd| {
+            let body = state_at(elements, *jd)?;
+            let earth = state_at(&earth, *jd)?;
+            Ok((*jd, body.distance_to(&earth)))
+        })
+        .collect()
+}
+
+/// Julian Dates from `start` to `stop` (inclusive) every `step` days, at most
+/// [`MAX_RANGE_POINTS`] of them. Empty if `stop` is before `start`.
+pub fn jd_range(start: f64, stop: f64, step: f64) -> Result<Vec<f64>, Box<dyn Error>> {
+    if !start.is_finite() || !stop.is_finite() {
+        return Err("range bounds must be finite".into());
+    }
+    if !(step > 0.0 && step.is_finite()) {
+        return Err(format!("step must be positive, got {}", step).into());
+    }
+    if stop < start {
+        return Ok(Vec::new());
+    }
+    let count = ((stop - start) / step).floor();
+    if count >= MAX_RANGE_POINTS as f64 {
+        return Err(format!(
+            "a step of {} days gives more than {} dates",
+            step, MAX_RANGE_POINTS
+        )
+        .into());
+    }
+    Ok((0..=count as usize)
+        .map(|i| start + i as f64 * step)
+        .collect())
+}
+
+/// Mean J2000 elements of the Earth-Moon barycenter (Standish, valid 1800-2050). Good to about
+/// 10^-4 au, which is enough for distance series but not for precise close approaches.
+pub fn earth_elements() -> OrbitElements {
+    let a = 1.00000261;
+    let e = 0.01671123;
+    let longitude_of_perihelion = 102.93768193;
+    let mean_longitude = 100.46457166;
+
+    OrbitElements {
+        epoch: J2000,
+        e,
+        a: Some(a),
+        q: a * (1.0 - e),
+        i: -0.00001531,
+        om: 0.0,
+        w: longitude_of_perihelion,
+        ma: Some(mean_longitude - longitude_of_perihelion),
+        tp: None,
+    }
+}
+
+/// Reads the `orbital_data` object of a NEO lookup response into [`OrbitElements`].
+pub fn elements_from_neo(orbital_data: &JsonValue) -> Result<OrbitElements, Box<dyn Error>> {
+    let required = |name: &str| {
+        field_f64(orbital_data, name).ok_or(format!("orbital_data is missing {}", name))
+    };
+
+    Ok(OrbitElements {
+        epoch: required("epoch_osculation")?,
+        e: required("eccentricity")?,
+        a: field_f64(orbital_data, "semi_major_axis"),
+        q: required("perihelion_distance")?,
+        i: required("inclination")?,
+        om: required("ascending_node_longitude")?,
+        w: required("perihelion_argument")?,
+        ma: field_f64(orbital_data, "mean_anomaly"),
+        tp: field_f64(orbital_data, "perihelion_time"),
+    })
+}
+
+fn mean_anomaly(elements: &OrbitElements, n: f64, jd: f64) -> Result<f64, Box<dyn Error>> {
+    match (elements.ma, elements.tp) {
+        (Some(ma), _) => Ok(ma.to_radians() + n * (jd - elements.epoch)),
+        (None, Some(tp)) => Ok(n * (jd - tp)),
+        (None, None) => Err("orbit needs either a mean anomaly or a time of perihelion".into()),
+    }
+}
+
+fn perifocal_to_ecliptic(elements: &OrbitElements) -> [[f64; 2]; 3] {
+    let (sin_om, cos_om) = elements.om.to_radians().sin_cos();
+    let (sin_w, cos_w) = elements.w.to_radians().sin_cos();
+    let (sin_i, cos_i) = elements.i.to_radians().sin_cos();
+
+    [
+        [
+            cos_om * cos_w - sin_om * sin_w * cos_i,
+            -cos_om * sin_w - sin_om * cos_w * cos_i,
+        ],
+        [
+            sin_om * cos_w + cos_om * sin_w * cos_i,
+            -sin_om * sin_w + cos_om * cos_w * cos_i,
+        ],
+        [sin_w * sin_i, cos_w * sin_i],
+    ]
+}
+
+fn rotate(rotation: &[[f64; 2]; 3], vector: [f64; 2]) -> [f64; 3] {
+    [
+        rotation[0][0] * vector[0] + rotation[0][1] * vector[1],
+        rotation[1][0] * vector[0] + rotation[1][1] * vector[1],
+        rotation[2][0] * vector[0] + rotation[2][1] * vector[1],
+    ]
+}
+
+fn norm(vector: [f64; 3]) -> f64 {
+    (vector[0] * vector[0] + vector[1] * vector[1] + vector[2] * vector[2]).sqrt()
+}
@@ -0,0 +1,336 @@
+use std::error::Error;
+use std::io::Read;
+
+use serde_json::Value as JsonValue;
+
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Host of the repository, which study file urls are relative to
+const OSDR_HOST: &str = "https://osdr.nasa.gov";
+
+/// Base Client for the Open Science Data Repository (GeneLab) search and study APIs.
+/// No api key is needed.
+#[derive(Debug, PartialEq)]
+pub struct OsdrClient {
+    base_url: String,
+    term: Option<String>,
+    sources: Vec<DataSource>,
+    filters: Vec<(String, String)>,
+    from: Option<u32>,
+    size: Option<u32>,
+}
+
+/// Repository the datasets are searched in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DataSource {
+    /// OSDR (GeneLab) studies
+    GeneLab,
+    /// NIH Gene Expression Omnibus
+    NihGeo,
+    /// EBI Proteomics Identification Database
+    EbiPride,
+    /// Metagenomics Rapid Annotation
+    MgRast,
+}
+
+impl DataSource {
+    fn as_str(&self) -> &'static str {
+        match self {
+            DataSource::GeneLab => "cgene",
+            DataSource::NihGeo => "nih_geo_gse",
+            DataSource::EbiPride => "ebi_pride",
+            DataSource::MgRast => "mg_rast",
+        }
+    }
+}
+
+impl OsdrClient {
+    pub fn new() -> Self {
+        OsdrClient {
+            base_url: String::from("https://osdr.nasa.gov/osdr/data"),
+            term: None,
+            sources: Vec::new(),
+            filters: Vec::new(),
+            from: None,
+            size: None,
+        }
+    }
+
+    /// Search terms, e.g. mouse liver
+    pub fn term(&mut self, term: &str) {
+        self.term = Some(String::from(term))
+    }
+
+    /// Repositories to search, every one by default
+    pub fn sources(&mut self, sources: &[DataSource]) {
+        self.sources = sources.to_vec()
+    }
+
+    /// Adds a filter on a field, e.g. `filter("organism", "Mus musculus")`. Filters on
+    /// different fields are combined.
+    pub fn filter(&mut self, field: &str, value: &str) {
+        self.filters
+            .push((String::from(field), String::from(value)))
+    }
+
+    pub fn clear_filters(&mut self) {
+        self.filters.clear()
+    }
+
+    /// Index of the first result, starting at 0
+    pub fn from(&mut self, from: u32) {
+        self.from = Some(from)
+    }
+
+    /// Results per page
+    pub fn size(&mut self, size: u32) {
+        self.size = Some(size)
+    }
+
+    pub fn search_url(&self) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&format!("{}/search", self.base_url));
+
+        if let Some(term) = &self.term {
+            url.param("term", term);
+        }
+        if !self.sources.is_empty() {
+            let types: Vec<&str> = self.sources.iter().map(DataSource::as_str).collect();
+            url.param("type", types.join(","));
+        }
+        for (field, value) in &self.filters {
+            url.param("ffield", field).param("fvalue", value);
+        }
+        if let Some(from) = self.from {
+            url.param("from", from);
+        }
+        match self.size {
+            Some(0) => return Err("size must be at least 1".into()),
+            Some(size) => {
+                url.param("size", size);
+            }
+            None => {}
+        }
+
+        Ok(url.build())
+    }
+
+    /// Files url of a study, by OSD number (87 for OSD-87)
+    pub fn files_url(&self, osd: u32) -> String {
+        format!("{}/osd/files/{}", self.base_url, osd)
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let osdr = into_response(res.as_str())?;
+
+        Ok(osdr)
+    }
+
+    /// OSDR: dataset search
+    pub fn query(&self) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.search_url()?)
+    }
+
+    /// Searches the datasets and parses the results into [`SearchResults`]
+    pub fn search(&self) -> Result<SearchResults, Box<dyn Error>> {
+        SearchResults::from_response(&self.query()?)
+    }
+
+    /// OSDR: metadata of a study, by OSD number
+    pub fn query_study(&self, osd: u32) -> Result<Response, Box<dyn Error>> {
+        self.get(&format!("{}/osd/meta/{}", self.base_url, osd))
+    }
+
+    /// Files of a study, by OSD number
+    pub fn study_files(&self, osd: u32) -> Result<Vec<StudyFile>, Box<dyn Error>> {
+        StudyFile::from_response(&self.get(&self.files_url(osd))?)
+    }
+
+    /// File manifest of a study as csv, one line per file with its absolute url.
+    /// # Example
+    /// ```no_run
+    /// use voyager_client::osdr::*;
+    ///
+    /// let base = OsdrClient::new();
+    /// std::fs::write("OSD-87_manifest.csv", base.manifest(87).unwrap()).unwrap();
+    /// ```
+    pub fn manifest(&self, osd: u32) -> Result<String, Box<dyn Error>> {
+        Ok(manifest_csv(&self.study_files(osd)?))
+    }
+
+    /// Downloads a study file
+    pub fn download(&self, file: &StudyFile) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut bytes = Vec::new();
+        ureq::get(&file.url())
+            .call()?
+            .into_reader()
+            .read_to_end(&mut bytes)?;
+
+        Ok(bytes)
+    }
+}
+
+impl Default for OsdrClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One page of dataset search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResults {
+    /// Number of results across every page
+    pub total: u64,
+    pub datasets: Vec<Dataset>,
+}
+
+impl SearchResults {
+    /// Parses a search response.
+    pub fn from_response(res: &Response) -> Result<SearchResults, Box<dyn Error>> {
+        let hits: JsonValue = res.field("hits")?;
+
+        let datasets = hits
+            .get("hits")
+            .and_then(JsonValue::as_array)
+            .ok_or("response has no hits")?
+            .iter()
+            .map(Dataset::from_hit)
+            .collect::<Vec<_>>();
+
+        // the total is a number, or {"value": n, "relation": "eq"} on newer search backends
+        let total = hits
+            .get("total")
+            .and_then(|total| total.as_u64().or_else(|| total.get("value")?.as_u64()))
+            .unwrap_or(datasets.len() as u64);
+
+        Ok(SearchResults { total, datasets })
+    }
+}
+
+/// A dataset in search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dataset {
+    /// Search index id
+    pub id: String,
+    /// e.g. OSD-87 or GSE12345
+    pub accession: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub organism: Option<String>,
+    /// e.g. Spaceflight Study
+    pub project_type: Option<String>,
+    /// Every field of the result, as indexed
+    pub source: JsonValue,
+}
+
+impl Dataset {
+    fn from_hit(hit: &JsonValue) -> Dataset {
+        let source = hit.get("_source").cloned().unwrap_or(JsonValue::Null);
+        let field = |key: &str| match source.get(key) {
+            Some(JsonValue::String(s)) if !s.trim().is_empty() => Some(s.clone()),
+            // some fields are lists of values
+            Some(JsonValue::Array(values)) => {
+                let values: Vec<&str> = values.iter().filter_map(JsonValue::as_str).collect();
+                (!values.is_empty()).then(|| values.join(", "))
+            }
+            _ => None,
+        };
+
+        Dataset {
+            id: hit
+                .get("_id")
+                .and_then(JsonValue::as_str)
+                .unwrap_or_default()
+                .to_string(),
+            accession: field("Accession"),
+            title: field("Study Title"),
+            description: field("Study Description"),
+            organism: field("organism"),
+            project_type: field("Project Type"),
+            source,
+        }
+    }
+}
+
+/// A file of a study.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StudyFile {
+    /// Study accession, e.g. OSD-87
+    pub study: String,
+    pub file_name: String,
+    /// e.g. Study Metadata Files or Raw Sequence Data
+    pub category: Option<String>,
+    /// Size in bytes
+    pub file_size: Option<u64>,
+    /// Download path, relative to the repository host
+    pub remote_url: String,
+}
+
+impl StudyFile {
+    /// Parses a study files response, which can list the files of several studies.
+    pub fn from_response(res: &Response) -> Result<Vec<StudyFile>, Box<dyn Error>> {
+        if res.field::<bool>("success").ok() == Some(false) {
+            return Err("study files request failed".into());
+        }
+
+        let mut files = Vec::new();
+        let studies: serde_json::Map<String, JsonValue> = res.field("studies")?;
+        for (study, listing) in &studies {
+            let listing = listing
+                .get("study_files")
+                .and_then(JsonValue::as_array)
+                .ok_or(format!("{} has no study_files", study))?;
+            for file in listing {
+                let string = |key: &str| file.get(key).and_then(JsonValue::as_str);
+                files.push(StudyFile {
+                    study: study.clone(),
+                    file_name: string("file_name").ok_or("file has no name")?.to_string(),
+                    category: string("category").map(String::from),
+                    file_size: file.get("file_size").and_then(JsonValue::as_u64),
+                    remote_url: string("remote_url").ok_or("file has no url")?.to_string(),
+                });
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Absolute download url
+    pub fn url(&self) -> String {
+        if self.remote_url.starts_with("http") {
+            self.remote_url.clone()
+        } else {
+            format!("{}{}", OSDR_HOST, self.remote_url)
+        }
+    }
+}
+
+/// Csv manifest of study files, with the header `study,file_name,category,file_size,url`.
+pub fn manifest_csv(files: &[StudyFile]) -> String {
+    let mut csv = String::from("study,file_name,category,file_size,url\n");
+    for file in files {
+        let row = [
+            file.study.clone(),
+            file.file_name.clone(),
+            file.category.clone().unwrap_or_default(),
+            file.file_size
+                .map(|size| size.to_string())
+                .unwrap_or_default(),
+            file.url(),
+        ];
+        let row: Vec<String> = row.iter().map(String::as_str).map(csv_field).collect();
+        csv.push_str(&row.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes a csv field if it holds a comma, quote or line break
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
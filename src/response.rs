@@ -1,5 +1,6 @@
 use serde_json::Value as JsonValue;
 use serde_derive::{Serialize, Deserialize};
+use serde::de::DeserializeOwned;
 use std::error::Error;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -22,6 +23,15 @@ impl Response {
         Ok(dump)
     }
 
+    /// Deserializes the value under a top level key of the body
+    pub(crate) fn field<T: DeserializeOwned>(&self, key: &str) -> Result<T, Box<dyn Error>> {
+        let value = self
+            .json
+            .get(key)
+            .ok_or(format!("response has no {}", key))?;
+        Ok(T::deserialize(value)?)
+    }
+
     pub fn to_pretty(&self) -> Result<String, Box<dyn Error>> {
         let json = self.json.clone();
         let pretty = serde_json::to_string_pretty(&json)?;
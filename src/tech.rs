@@ -1,8 +1,12 @@
 use std::error::Error;
 
+use serde_json::Value as JsonValue;
+
 use crate::key;
 use crate::response::*;
+use crate::url_builder::UrlBuilder;
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Collections {
     Patent,
     PatentIssued,
@@ -13,46 +17,287 @@ pub enum Collections {
 #[derive(Debug)]
 pub struct TechTransferClient {
     base_url: String,
+    collection: Collections,
+    page: Option<u32>,
 }
 
 impl TechTransferClient {
     pub fn new() -> Self {
         TechTransferClient {
-            base_url: String::from("https://api.nasa.gov/techtransfer/patent/?"),
+            base_url: String::from("https://api.nasa.gov/techtransfer/patent/"),
+            collection: Collections::Patent,
+            page: None,
         }
     }
 
     /// Switches Collection
     pub fn switch(&mut self, collection: Collections) -> Result<(), Box<dyn Error>> {
+        self.collection = collection;
         match collection {
             Collections::Patent => {
-                self.base_url = String::from("https://api.nasa.gov/techtransfer/patent/?");
+                self.base_url = String::from("https://api.nasa.gov/techtransfer/patent/");
                 Ok(())
             }
             Collections::PatentIssued => {
                 self.base_url =
-                    String::from("https://api.nasa.gov/techtransfer/patent_issued/?");
+                    String::from("https://api.nasa.gov/techtransfer/patent_issued/");
                 Ok(())
             }
             Collections::Software => {
-                self.base_url = String::from("https://api.nasa.gov/techtransfer/software/?");
+                self.base_url = String::from("https://api.nasa.gov/techtransfer/software/");
                 Ok(())
             }
             Collections::Spinoff => {
-                self.base_url = String::from("https://api.nasa.gov/techtransfer/spinoff/?");
+                self.base_url = String::from("https://api.nasa.gov/techtransfer/spinoff/");
                 Ok(())
             }
         }
     }
 
+    /// Page of results to return, starting at 0
+    pub fn page(&mut self, page: u32) {
+        self.page = Some(page)
+    }
+
     pub fn query(&self, query: String) -> Result<Response, Box<dyn Error>> {
+        self.query_page(&query, self.page)
+    }
+
+    fn query_page(&self, query: &str, page: Option<u32>) -> Result<Response, Box<dyn Error>> {
         let key: String = key::from_dotenv()?;
 
-        let url = format!("{}{}&api_key={}", self.base_url, query, key);
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.term(query);
+        if let Some(page) = page {
+            url.param("page", page);
+        }
+        url.param("api_key", key);
 
-        let res: String = ureq::get(&url).call()?.into_string()?;
-        let tech = into_response(res.as_str()).unwrap();
+        let res: String = ureq::get(&url.build()).call()?.into_string()?;
+        let tech = into_response(res.as_str())?;
 
         Ok(tech)
     }
-}
\ No newline at end of file
+
+    /// Searches the collection and parses the results into a [`TechPage`]
+    pub fn search(&self, query: &str) -> Result<TechPage, Box<dyn Error>> {
+        TechPage::from_response(self.collection, &self.query_page(query, self.page)?)
+    }
+
+    /// Iterates over every page of a search, starting at the page set with
+    /// [`TechTransferClient::page`] (or the first one).
+    /// # Example
+    /// ```no_run
+    /// use voyager_client::tech::*;
+    ///
+    /// let base = TechTransferClient::new();
+    /// for page in base.pages("engine") {
+    ///     for record in page.unwrap().records {
+    ///         if let TechRecord::Patent(patent) = record {
+    ///             println!("{}: {}", patent.case_number, patent.title);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn pages<'a>(&'a self, query: &'a str) -> TechPages<'a> {
+        TechPages {
+            client: self,
+            query,
+            page: self.page.unwrap_or(0),
+            done: false,
+        }
+    }
+}
+
+/// Iterator over the pages of a Tech Transfer search, see [`TechTransferClient::pages`].
+/// Stops after the last page, or after the first error.
+pub struct TechPages<'a> {
+    client: &'a TechTransferClient,
+    query: &'a str,
+    page: u32,
+    done: bool,
+}
+
+impl Iterator for TechPages<'_> {
+    type Item = Result<TechPage, Box<dyn Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let page = self
+            .client
+            .query_page(self.query, Some(self.page))
+            .and_then(|res| TechPage::from_response(self.client.collection, &res));
+
+        match &page {
+            Ok(page) if page.records.is_empty() => return None,
+            // count with our own index, the page number is not always echoed back
+            Ok(page) => self.done = past_total(self.page, page.perpage, page.total),
+            Err(_) => self.done = true,
+        }
+        self.page = self.page.saturating_add(1);
+        Some(page)
+    }
+}
+
+/// One page of Tech Transfer search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TechPage {
+    /// Number of results on this page
+    pub count: u32,
+    /// Number of results across every page
+    pub total: u32,
+    /// Results per page
+    pub perpage: u32,
+    /// Index of this page, starting at 0
+    pub page: u32,
+    pub records: Vec<TechRecord>,
+}
+
+impl TechPage {
+    /// Parses a search response from the given collection.
+    pub fn from_response(
+        collection: Collections,
+        res: &Response,
+    ) -> Result<TechPage, Box<dyn Error>> {
+        let json = res.json()?;
+        let number = |key: &str| json.get(key).and_then(JsonValue::as_u64).map(|n| n as u32);
+
+        let records = json
+            .get("results")
+            .and_then(JsonValue::as_array)
+            .ok_or("response has no results")?
+            .iter()
+            .map(|row| TechRecord::from_row(collection, row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TechPage {
+            count: number("count").unwrap_or(records.len() as u32),
+            total: number("total").unwrap_or(records.len() as u32),
+            perpage: number("perpage").unwrap_or(records.len() as u32),
+            page: number("page").unwrap_or(0),
+            records,
+        })
+    }
+
+    /// True when no results are left after this page
+    pub fn is_last(&self) -> bool {
+        past_total(self.page, self.perpage, self.total)
+    }
+}
+
+/// True when the pages up to `page` cover `total` results, computed in u64 so large page
+/// numbers can't overflow
+fn past_total(page: u32, perpage: u32, total: u32) -> bool {
+    perpage == 0 || (page as u64 + 1) * perpage as u64 >= total as u64
+}
+
+/// A search result, typed after the collection it came from.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TechRecord {
+    /// From the patent or patent_issued collections
+    Patent(Patent),
+    Software(Software),
+    Spinoff(Spinoff),
+}
+
+impl TechRecord {
+    fn from_row(collection: Collections, row: &JsonValue) -> Result<TechRecord, Box<dyn Error>> {
+        let columns = row.as_array().ok_or("result is not an array")?;
+        let column = |i: usize| match columns.get(i) {
+            Some(JsonValue::String(s)) if !s.trim().is_empty() => Some(strip_tags(s)),
+            Some(JsonValue::Number(n)) => Some(n.to_string()),
+            _ => None,
+        };
+        let id = column(0).ok_or("result has no id")?;
+
+        Ok(match collection {
+            Collections::Patent | Collections::PatentIssued => TechRecord::Patent(Patent {
+                id,
+                case_number: column(1).unwrap_or_default(),
+                title: column(2).unwrap_or_default(),
+                description: column(3),
+                reference_number: column(4),
+                category: column(5),
+                center: column(9),
+                image_url: column(10),
+            }),
+            Collections::Software => TechRecord::Software(Software {
+                id,
+                case_number: column(1).unwrap_or_default(),
+                title: column(2).unwrap_or_default(),
+                description: column(3),
+                category: column(5),
+                release_type: column(6),
+                center: column(9),
+            }),
+            Collections::Spinoff => TechRecord::Spinoff(Spinoff {
+                id,
+                title: column(2).unwrap_or_default(),
+                description: column(3),
+                category: column(5),
+                center: column(9),
+                image_url: column(10),
+            }),
+        })
+    }
+}
+
+/// A NASA patent available for licensing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Patent {
+    pub id: String,
+    /// NASA case number, e.g. LEW-TOPS-99
+    pub case_number: String,
+    pub title: String,
+    pub description: Option<String>,
+    /// Patent or application reference number
+    pub reference_number: Option<String>,
+    pub category: Option<String>,
+    /// NASA center that owns the technology, e.g. GRC
+    pub center: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// A NASA software release.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Software {
+    pub id: String,
+    /// NASA case number, e.g. ARC-16133-1
+    pub case_number: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    /// Release type, e.g. General Public Release or Open Source
+    pub release_type: Option<String>,
+    /// NASA center that released the software
+    pub center: Option<String>,
+}
+
+/// A commercial spinoff of NASA technology.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Spinoff {
+    pub id: String,
+    pub title: String,
+    pub description: Option<String>,
+    pub category: Option<String>,
+    /// NASA center the technology came from
+    pub center: Option<String>,
+    pub image_url: Option<String>,
+}
+
+/// Removes the html tags the API uses to highlight search terms.
+fn strip_tags(text: &str) -> String {
+    let mut stripped = String::with_capacity(text.len());
+    let mut in_tag = false;
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(c),
+            _ => {}
+        }
+    }
+    stripped
+}
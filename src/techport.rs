@@ -0,0 +1,266 @@
+use std::error::Error;
+
+use chrono::NaiveDate;
+use serde_json::Value as JsonValue;
+
+use crate::key;
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+/// Base Client for the TechPort API, NASA's technology portfolio.
+///
+/// The API has changed its field names over time (`id` and `projectId`, `startDate` and
+/// `startDateString`...), projects are parsed from either.
+#[derive(Debug, PartialEq)]
+pub struct TechPortClient {
+    base_url: String,
+}
+
+impl TechPortClient {
+    pub fn new() -> Self {
+        TechPortClient {
+            base_url: String::from("https://api.nasa.gov/techport/api"),
+        }
+    }
+
+    /// Projects url, without the api key
+    pub fn projects_url(&self, updated_since: &str) -> Result<String, Box<dyn Error>> {
+        Ok(self.projects_builder(updated_since)?.build())
+    }
+
+    fn projects_builder(&self, updated_since: &str) -> Result<UrlBuilder, Box<dyn Error>> {
+        NaiveDate::parse_from_str(updated_since, "%Y-%m-%d")
+            .map_err(|_| format!("{} is not a YYYY-MM-DD date", updated_since))?;
+
+        let mut url = UrlBuilder::new(&format!("{}/projects", self.base_url));
+        url.param("updatedSince", updated_since);
+        Ok(url)
+    }
+
+    fn get(&self, mut url: UrlBuilder) -> Result<Response, Box<dyn Error>> {
+        let key: String = key::from_dotenv()?;
+        url.param("api_key", key);
+
+        let res: String = ureq::get(&url.build()).call()?.into_string()?;
+        let techport = into_response(res.as_str())?;
+
+        Ok(techport)
+    }
+
+    /// TechPort: ids of the projects updated since a date, formatted YYYY-MM-DD
+    pub fn query(&self, updated_since: &str) -> Result<Response, Box<dyn Error>> {
+        self.get(self.projects_builder(updated_since)?)
+    }
+
+    /// TechPort: a project's details
+    pub fn query_project(&self, id: u64) -> Result<Response, Box<dyn Error>> {
+        self.get(UrlBuilder::new(&format!(
+            "{}/projects/{}",
+            self.base_url, id
+        )))
+    }
+
+    /// Projects updated since a date, formatted YYYY-MM-DD
+    pub fn updated_since(
+        &self,
+        updated_since: &str,
+    ) -> Result<Vec<ProjectSummary>, Box<dyn Error>> {
+        ProjectSummary::from_response(&self.query(updated_since)?)
+    }
+
+    pub fn project(&self, id: u64) -> Result<Project, Box<dyn Error>> {
+        Project::from_response(&self.query_project(id)?)
+    }
+
+    /// Fetches the details of every project updated since the last sync. Keep
+    /// [`TechPortSync::next_since`] for the next call: it is the latest update date seen, so
+    /// projects updated later that same day are picked up again rather than missed.
+    ///
+    /// Every updated project is one request, syncing from an old date takes a while.
+    /// # Example
+    /// ```no_run
+    /// use chrono::NaiveDate;
+    /// use voyager_client::techport::*;
+    ///
+    /// let base = TechPortClient::new();
+    /// let mut since = NaiveDate::from_ymd(2024, 1, 1);
+    ///
+    /// let sync = base.sync(since).unwrap();
+    /// for project in &sync.projects {
+    ///     println!("{} TRL {:?}", project.title, project.trl_current);
+    /// }
+    /// since = sync.next_since;
+    /// ```
+    pub fn sync(&self, since: NaiveDate) -> Result<TechPortSync, Box<dyn Error>> {
+        let updated = self.updated_since(&since.format("%Y-%m-%d").to_string())?;
+        let projects = updated
+            .iter()
+            .map(|summary| self.project(summary.project_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TechPortSync {
+            next_since: next_since(since, &updated),
+            projects,
+        })
+    }
+}
+
+impl Default for TechPortClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Outcome of [`TechPortClient::sync`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TechPortSync {
+    pub projects: Vec<Project>,
+    /// Date to sync from next time
+    pub next_since: NaiveDate,
+}
+
+/// Latest update date of the summaries, or `since` if there are none.
+pub fn next_since(since: NaiveDate, updated: &[ProjectSummary]) -> NaiveDate {
+    updated
+        .iter()
+        .filter_map(|summary| summary.last_updated)
+        .fold(since, NaiveDate::max)
+}
+
+/// A project in the updated projects list.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectSummary {
+    pub project_id: u64,
+    pub last_updated: Option<NaiveDate>,
+}
+
+impl ProjectSummary {
+    /// Parses a projects list response.
+    pub fn from_response(res: &Response) -> Result<Vec<ProjectSummary>, Box<dyn Error>> {
+        let mut projects: JsonValue = res.field("projects")?;
+        // older responses nest the list: {"projects": {"totalCount": 1, "projects": [...]}}
+        if let Some(nested) = projects.get_mut("projects").map(JsonValue::take) {
+            projects = nested;
+        }
+
+        projects
+            .as_array()
+            .ok_or("projects is not an array")?
+            .iter()
+            .map(|project| {
+                Ok(ProjectSummary {
+                    project_id: project_id(project)?,
+                    last_updated: string(project, &["lastUpdated"])
+                        .and_then(|date| NaiveDate::parse_from_str(&date, "%Y-%m-%d").ok()),
+                })
+            })
+            .collect()
+    }
+}
+
+/// A technology project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Project {
+    pub project_id: u64,
+    pub title: String,
+    /// e.g. Active or Completed
+    pub status: Option<String>,
+    pub description: Option<String>,
+    pub benefits: Option<String>,
+    /// Technology readiness level when the project started (1-9)
+    pub trl_begin: Option<u8>,
+    pub trl_current: Option<u8>,
+    /// Technology readiness level targeted at the end of the project
+    pub trl_end: Option<u8>,
+    /// e.g. Oct 2015 or 2015-10-01, as given by the API
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub last_updated: Option<String>,
+    pub lead_organization: Option<Organization>,
+    pub supporting_organizations: Vec<Organization>,
+    pub website: Option<String>,
+}
+
+impl Project {
+    /// Parses a project details response.
+    pub fn from_response(res: &Response) -> Result<Project, Box<dyn Error>> {
+        Project::from_json(&res.field("project")?)
+    }
+
+    fn from_json(project: &JsonValue) -> Result<Project, Box<dyn Error>> {
+        // readiness levels run from 1 to 9, anything else is left out
+        let trl = |keys: &[&str]| {
+            number(project, keys)
+                .filter(|trl| trl.fract() == 0.0 && (1.0..=9.0).contains(trl))
+                .map(|trl| trl as u8)
+        };
+
+        Ok(Project {
+            project_id: project_id(project)?,
+            title: string(project, &["title"]).unwrap_or_default(),
+            status: string(project, &["statusDescription", "status"]),
+            description: string(project, &["description"]),
+            benefits: string(project, &["benefits"]),
+            trl_begin: trl(&["trlBegin", "startTrl"]),
+            trl_current: trl(&["trlCurrent", "currentTrl"]),
+            trl_end: trl(&["trlEnd", "endTrl"]),
+            start_date: string(project, &["startDateString", "startDate"]),
+            end_date: string(project, &["endDateString", "endDate"]),
+            last_updated: string(project, &["lastUpdated"]),
+            lead_organization: project
+                .get("leadOrganization")
+                .and_then(Organization::from_json),
+            supporting_organizations: project
+                .get("supportingOrganizations")
+                .and_then(JsonValue::as_array)
+                .map(|orgs| orgs.iter().filter_map(Organization::from_json).collect())
+                .unwrap_or_default(),
+            website: string(project, &["website"]),
+        })
+    }
+}
+
+/// An organization leading or supporting a project.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Organization {
+    pub name: String,
+    /// e.g. NASA Center, Industry or Academia
+    pub organization_type: Option<String>,
+    pub city: Option<String>,
+}
+
+impl Organization {
+    fn from_json(org: &JsonValue) -> Option<Organization> {
+        Some(Organization {
+            name: string(org, &["organizationName", "name"])?,
+            organization_type: string(org, &["organizationTypePretty", "organizationType", "type"]),
+            city: string(org, &["city"]),
+        })
+    }
+}
+
+/// Project id, which must be a non-negative integer
+fn project_id(project: &JsonValue) -> Result<u64, Box<dyn Error>> {
+    let id = number(project, &["projectId", "id"]).ok_or("project has no id")?;
+    if id < 0.0 || id.fract() != 0.0 || id >= u64::MAX as f64 {
+        return Err(format!("{} is not a valid project id", id).into());
+    }
+    Ok(id as u64)
+}
+
+/// First non-empty string among the keys
+fn string(json: &JsonValue, keys: &[&str]) -> Option<String> {
+    keys.iter().find_map(|key| match json.get(key)? {
+        JsonValue::String(s) if !s.trim().is_empty() => Some(s.clone()),
+        _ => None,
+    })
+}
+
+/// First number among the keys, numeric strings included
+fn number(json: &JsonValue, keys: &[&str]) -> Option<f64> {
+    keys.iter().find_map(|key| match json.get(key)? {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.trim().parse().ok(),
+        _ => None,
+    })
+}
@@ -1,3 +1,5 @@
+use std::error::Error;
+
 use chrono::prelude::*;
 
 /// Returns the current date in YYYY-MM-DD format as a String
@@ -42,4 +44,190 @@ pub fn one_month() -> String {
         let start = format!("{}-{}-{}", local.year(), local.month() - 1, local.day());
         start
     }
-}
\ No newline at end of file
+}
+
+/// Julian Date of the Unix epoch, 1970-01-01 00:00
+const UNIX_EPOCH_JD: f64 = 2440587.5;
+
+/// Modified Julian Date of the Unix epoch
+const UNIX_EPOCH_MJD: f64 = 40587.0;
+
+/// Offset between Julian Date and Modified Julian Date (MJD = JD - 2400000.5)
+pub const MJD_OFFSET: f64 = 2400000.5;
+
+/// TAI - UTC (s) from the start of each month a leap second took effect
+const LEAP_SECONDS: [(i32, u32, f64); 28] = [
+    (1972, 1, 10.0),
+    (1972, 7, 11.0),
+    (1973, 1, 12.0),
+    (1974, 1, 13.0),
+    (1975, 1, 14.0),
+    (1976, 1, 15.0),
+    (1977, 1, 16.0),
+    (1978, 1, 17.0),
+    (1979, 1, 18.0),
+    (1980, 1, 19.0),
+    (1981, 7, 20.0),
+    (1982, 7, 21.0),
+    (1983, 7, 22.0),
+    (1985, 7, 23.0),
+    (1988, 1, 24.0),
+    (1990, 1, 25.0),
+    (1991, 1, 26.0),
+    (1992, 7, 27.0),
+    (1993, 7, 28.0),
+    (1994, 7, 29.0),
+    (1996, 1, 30.0),
+    (1997, 7, 31.0),
+    (1999, 1, 32.0),
+    (2006, 1, 33.0),
+    (2009, 1, 34.0),
+    (2012, 7, 35.0),
+    (2015, 7, 36.0),
+    (2017, 1, 37.0),
+];
+
+fn unix_seconds(datetime: NaiveDateTime) -> f64 {
+    datetime.timestamp() as f64 + datetime.timestamp_subsec_nanos() as f64 * 1e-9
+}
+
+fn from_unix_days(days: f64) -> Result<NaiveDateTime, Box<dyn Error>> {
+    let millis = (days * 86_400_000.0).round();
+    if !millis.is_finite() || millis.abs() > i64::MAX as f64 {
+        return Err(format!("{} days is out of range", days).into());
+    }
+    let millis = millis as i64;
+    NaiveDateTime::from_timestamp_opt(
+        millis.div_euclid(1000),
+        (millis.rem_euclid(1000) * 1_000_000) as u32,
+    )
+    .ok_or_else(|| format!("{} days is out of range", days).into())
+}
+
+/// Julian Date of a date and time, in the same time scale as the input
+/// # Example
+/// ```
+/// use chrono::NaiveDate;
+/// use voyager_client::time;
+///
+/// let j2000 = NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0);
+/// assert_eq!(time::julian_date(j2000), 2451545.0);
+/// ```
+pub fn julian_date(datetime: NaiveDateTime) -> f64 {
+    UNIX_EPOCH_JD + unix_seconds(datetime) / 86400.0
+}
+
+/// Date and time of a Julian Date, to the millisecond
+pub fn from_julian_date(jd: f64) -> Result<NaiveDateTime, Box<dyn Error>> {
+    from_unix_days(jd - UNIX_EPOCH_JD)
+}
+
+/// Modified Julian Date of a date and time, in the same time scale as the input
+pub fn modified_julian_date(datetime: NaiveDateTime) -> f64 {
+    UNIX_EPOCH_MJD + unix_seconds(datetime) / 86400.0
+}
+
+/// Date and time of a Modified Julian Date, to the millisecond
+pub fn from_modified_julian_date(mjd: f64) -> Result<NaiveDateTime, Box<dyn Error>> {
+    from_unix_days(mjd - UNIX_EPOCH_MJD)
+}
+
+/// Modified Julian Date at 00:00 of a calendar date, as used by the mdesign `mjd0` parameter
+pub fn mjd_of_date(date: NaiveDate) -> i64 {
+    (date - NaiveDate::from_ymd(1858, 11, 17)).num_days()
+}
+
+/// TAI - UTC (s). Dates before 1972 use the initial 10 s offset.
+fn tai_minus_utc(utc: NaiveDateTime) -> f64 {
+    LEAP_SECONDS
+        .iter()
+        .rev()
+        .find(|(year, month, _)| (utc.year(), utc.month()) >= (*year, *month))
+        .map_or(10.0, |(_, _, offset)| *offset)
+}
+
+/// TDB - TT (s), from the leading periodic terms of the Earth's orbit
+fn tdb_minus_tt(jd: f64) -> f64 {
+    let g = (357.53 + 0.98560028 * (jd - 2451545.0)).to_radians();
+    0.001657 * g.sin() + 0.000014 * (2.0 * g).sin()
+}
+
+fn add_seconds(datetime: NaiveDateTime, seconds: f64) -> NaiveDateTime {
+    datetime + chrono::Duration::nanoseconds((seconds * 1e9).round() as i64)
+}
+
+/// TDB - UTC (s) at a UTC date and time
+pub fn tdb_minus_utc(utc: NaiveDateTime) -> f64 {
+    tai_minus_utc(utc) + 32.184 + tdb_minus_tt(julian_date(utc))
+}
+
+/// Converts a UTC date and time to Barycentric Dynamical Time (TDB)
+pub fn utc_to_tdb(utc: NaiveDateTime) -> NaiveDateTime {
+    add_seconds(utc, tdb_minus_utc(utc))
+}
+
+/// Converts a Barycentric Dynamical Time (TDB) date and time to UTC
+pub fn tdb_to_utc(tdb: NaiveDateTime) -> NaiveDateTime {
+    // the offset changes by at most a second around leap seconds, so two passes settle it
+    let mut utc = add_seconds(tdb, -tdb_minus_utc(tdb));
+    utc = add_seconds(tdb, -tdb_minus_utc(utc));
+    utc
+}
+
+/// Formats a date and time the way JPL APIs print calendar dates, e.g. 2022-Jan-01 12:00
+pub fn to_jpl_format(datetime: NaiveDateTime) -> String {
+    datetime.format("%Y-%b-%d %H:%M").to_string()
+}
+
+/// Parses a JPL calendar date, e.g. `2022-Jan-01 12:00`, `2022-Jan-01 12:00:00.0000`,
+/// `A.D. 2022-Jan-01` or ISO `2022-01-01T12:00:00`, into UTC.
+///
+/// A trailing `TDB` (or `TT`) time scale is converted to UTC, `UTC` or no suffix is taken as is.
+/// # Example
+/// ```
+/// use voyager_client::time;
+///
+/// let date = time::parse_jpl_date("2022-Jan-01 12:00").unwrap();
+/// assert_eq!(time::to_jpl_format(date), "2022-Jan-01 12:00");
+///
+/// let tdb = time::parse_jpl_date("2022-Jan-01 12:01:09.184 TDB").unwrap();
+/// assert_eq!(time::to_jpl_format(tdb), "2022-Jan-01 12:00");
+/// ```
+pub fn parse_jpl_date(date: &str) -> Result<NaiveDateTime, Box<dyn Error>> {
+    let mut text = date.trim();
+    text = text.strip_prefix("A.D.").unwrap_or(text).trim();
+
+    let mut scale = "UTC";
+    for suffix in ["UTC", "TDB", "TT"].iter() {
+        if let Some(rest) = text.strip_suffix(suffix) {
+            scale = suffix;
+            text = rest.trim();
+            break;
+        }
+    }
+
+    let datetime_formats = [
+        "%Y-%b-%d %H:%M:%S%.f",
+        "%Y-%b-%d %H:%M",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+    ];
+    let parsed = datetime_formats
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(text, format).ok())
+        .or_else(|| {
+            ["%Y-%b-%d", "%Y-%m-%d"]
+                .iter()
+                .find_map(|format| NaiveDate::parse_from_str(text, format).ok())
+                .map(|day| day.and_hms(0, 0, 0))
+        })
+        .ok_or_else(|| format!("{} is not a recognized JPL date", date))?;
+
+    Ok(match scale {
+        "TDB" => tdb_to_utc(parsed),
+        // TT and TDB differ by less than 2 ms, below the precision of the JPL formats
+        "TT" => add_seconds(parsed, -(tai_minus_utc(parsed) + 32.184)),
+        _ => parsed,
+    })
+}
@@ -0,0 +1,305 @@
+use std::error::Error;
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use serde_derive::Deserialize;
+
+use crate::response::*;
+use crate::url_builder::UrlBuilder;
+
+mod sgp4;
+pub use sgp4::*;
+
+/// Base Client for the TLE API (tle.ivanstanojevic.me), which serves two-line element sets
+/// searchable by satellite name. No api key is needed.
+#[derive(Debug, PartialEq)]
+pub struct TleClient {
+    base_url: String,
+    page: Option<u32>,
+    page_size: Option<u32>,
+}
+
+impl TleClient {
+    pub fn new() -> Self {
+        TleClient {
+            base_url: String::from("https://tle.ivanstanojevic.me/api/tle"),
+            page: None,
+            page_size: None,
+        }
+    }
+
+    /// Page of search results, starting at 1
+    pub fn page(&mut self, page: u32) {
+        self.page = Some(page)
+    }
+
+    /// Search results per page, 20 by default and at most 100
+    pub fn page_size(&mut self, page_size: u32) {
+        self.page_size = Some(page_size)
+    }
+
+    pub fn search_url(&self, search: &str) -> Result<String, Box<dyn Error>> {
+        let mut url = UrlBuilder::new(&self.base_url);
+        url.param("search", search);
+        match self.page {
+            Some(0) => return Err("pages start at 1".into()),
+            Some(page) => {
+                url.param("page", page);
+            }
+            None => {}
+        }
+        match self.page_size {
+            Some(size) if size == 0 || size > 100 => {
+                return Err("page size must be between 1 and 100".into())
+            }
+            Some(size) => {
+                url.param("page-size", size);
+            }
+            None => {}
+        }
+
+        Ok(url.build())
+    }
+
+    fn get(&self, url: &str) -> Result<Response, Box<dyn Error>> {
+        let res: String = ureq::get(url).call()?.into_string()?;
+        let tle = into_response(res.as_str())?;
+
+        Ok(tle)
+    }
+
+    /// TLE: element sets of the satellites whose name matches
+    pub fn query(&self, search: &str) -> Result<Response, Box<dyn Error>> {
+        self.get(&self.search_url(search)?)
+    }
+
+    /// Searches satellites by name, e.g. ISS or STARLINK
+    pub fn search(&self, search: &str) -> Result<TlePage, Box<dyn Error>> {
+        TlePage::from_response(&self.query(search)?)
+    }
+
+    /// Latest element set of a satellite, by NORAD catalog number (25544 for the ISS)
+    pub fn fetch(&self, satellite_id: u32) -> Result<TleRecord, Box<dyn Error>> {
+        let res = self.get(&format!("{}/{}", self.base_url, satellite_id))?;
+        Ok(serde_json::from_value(res.json()?)?)
+    }
+}
+
+impl Default for TleClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TlePage {
+    /// Number of results across every page
+    #[serde(rename = "totalItems")]
+    pub total_items: u32,
+    #[serde(rename = "member")]
+    pub members: Vec<TleRecord>,
+}
+
+impl TlePage {
+    /// Parses a search response.
+    pub fn from_response(res: &Response) -> Result<TlePage, Box<dyn Error>> {
+        Ok(serde_json::from_value(res.json()?)?)
+    }
+}
+
+/// An element set as served by the API, lines unparsed.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TleRecord {
+    /// NORAD catalog number
+    pub satellite_id: u32,
+    pub name: String,
+    /// Time the element set was published, e.g. 2024-01-17T12:19:34+00:00
+    pub date: String,
+    pub line1: String,
+    pub line2: String,
+}
+
+impl TleRecord {
+    /// Parses and validates the lines.
+    pub fn tle(&self) -> Result<Tle, Box<dyn Error>> {
+        let mut tle = Tle::parse(&self.line1, &self.line2)?;
+        tle.name = Some(self.name.clone());
+        Ok(tle)
+    }
+}
+
+/// Mean orbital elements of a two-line element set. Angles are in degrees.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tle {
+    /// From the title line, if there was one
+    pub name: Option<String>,
+    /// NORAD catalog number
+    pub satellite_number: u32,
+    /// U (unclassified), C or S
+    pub classification: char,
+    /// Launch year, launch number and piece, e.g. 98067A
+    pub international_designator: String,
+    /// Epoch of the elements (UTC)
+    pub epoch: NaiveDateTime,
+    /// First derivative of the mean motion divided by two (rev/day^2)
+    pub mean_motion_dot: f64,
+    /// Second derivative of the mean motion divided by six (rev/day^3)
+    pub mean_motion_ddot: f64,
+    /// Drag term (1/earth radii)
+    pub bstar: f64,
+    pub element_set_number: u32,
+    pub inclination: f64,
+    /// Right ascension of the ascending node
+    pub raan: f64,
+    pub eccentricity: f64,
+    pub arg_perigee: f64,
+    pub mean_anomaly: f64,
+    /// Revolutions per day
+    pub mean_motion: f64,
+    /// Revolutions at epoch
+    pub revolution_number: u32,
+}
+
+impl Tle {
+    /// Parses the two lines of an element set, checking their layout and checksums.
+    pub fn parse(line1: &str, line2: &str) -> Result<Tle, Box<dyn Error>> {
+        let line1 = line1.trim_end();
+        let line2 = line2.trim_end();
+        for (number, line) in [('1', line1), ('2', line2)] {
+            if line.len() != 69 || !line.is_ascii() {
+                return Err(format!("line {} is not 69 ascii characters", number).into());
+            }
+            if !line.starts_with(number) {
+                return Err(format!("line {} does not start with {}", number, number).into());
+            }
+            let expected = checksum(line);
+            if line.as_bytes()[68] != b'0' + expected {
+                return Err(format!(
+                    "line {} checksum is {}, expected {}",
+                    number,
+                    &line[68..],
+                    expected
+                )
+                .into());
+            }
+        }
+
+        let field = |line: &str, start: usize, end: usize| line[start..end].trim().to_string();
+        let number = |line: &str, start: usize, end: usize, name: &str| {
+            field(line, start, end)
+                .parse::<f64>()
+                .map_err(|_| format!("{} is not a number: {}", name, &line[start..end]))
+        };
+        let integer = |line: &str, start: usize, end: usize, name: &str| {
+            field(line, start, end)
+                .parse::<u32>()
+                .map_err(|_| format!("{} is not an integer: {}", name, &line[start..end]))
+        };
+
+        let satellite_number: u32 = field(line1, 2, 7).parse()?;
+        if field(line2, 2, 7).parse::<u32>()? != satellite_number {
+            return Err("lines are of different satellites".into());
+        }
+
+        let year = field(line1, 18, 20).parse::<i32>()?;
+        let year = if year < 57 { 2000 + year } else { 1900 + year };
+        let day = number(line1, 20, 32, "epoch day")?;
+        let epoch = NaiveDate::from_ymd_opt(year, 1, 1)
+            .ok_or("epoch year is invalid")?
+            .and_hms(0, 0, 0)
+            + Duration::microseconds(((day - 1.0) * 86_400_000_000.0).round() as i64);
+
+        Ok(Tle {
+            name: None,
+            satellite_number,
+            classification: line1.as_bytes()[7] as char,
+            international_designator: field(line1, 9, 17),
+            epoch,
+            mean_motion_dot: number(line1, 33, 43, "mean motion derivative")?,
+            mean_motion_ddot: implied_decimal(&line1[44..52])?,
+            bstar: implied_decimal(&line1[53..61])?,
+            element_set_number: integer(line1, 64, 68, "element set number")?,
+            inclination: number(line2, 8, 16, "inclination")?,
+            raan: number(line2, 17, 25, "right ascension")?,
+            eccentricity: format!("0.{}", field(line2, 26, 33)).parse()?,
+            arg_perigee: number(line2, 34, 42, "argument of perigee")?,
+            mean_anomaly: number(line2, 43, 51, "mean anomaly")?,
+            mean_motion: number(line2, 52, 63, "mean motion")?,
+            revolution_number: integer(line2, 63, 68, "revolution number")?,
+        })
+    }
+
+    /// Parses element sets from text, such as a cached CelesTrak file. Title lines are
+    /// optional; blank lines are skipped.
+    pub fn parse_lines(text: &str) -> Result<Vec<Tle>, Box<dyn Error>> {
+        let mut tles = Vec::new();
+        let mut name = None;
+        let mut lines = text
+            .lines()
+            .map(str::trim_end)
+            .filter(|l| !l.trim().is_empty());
+
+        while let Some(line) = lines.next() {
+            if line.starts_with("1 ") {
+                let line2 = lines.next().ok_or("line 1 has no line 2")?;
+                let mut tle = Tle::parse(line, line2)?;
+                tle.name = name.take();
+                tles.push(tle);
+            } else {
+                name = Some(line.trim_start_matches("0 ").trim().to_string());
+            }
+        }
+
+        Ok(tles)
+    }
+
+    /// Orbital period (minutes)
+    pub fn period(&self) -> f64 {
+        1440.0 / self.mean_motion
+    }
+
+    /// SGP4 propagator of the element set, see [`Sgp4`]
+    pub fn sgp4(&self) -> Result<Sgp4, Box<dyn Error>> {
+        Sgp4::new(self)
+    }
+}
+
+/// Checksum of a line: the sum of its first 68 digits, minus signs counting as 1, modulo 10.
+pub fn checksum(line: &str) -> u8 {
+    let sum: u32 = line
+        .bytes()
+        .take(68)
+        .map(|byte| match byte {
+            b'0'..=b'9' => (byte - b'0') as u32,
+            b'-' => 1,
+            _ => 0,
+        })
+        .sum();
+    (sum % 10) as u8
+}
+
+/// Reads fields such as ` 28098-4` (0.28098e-4) or `-11606-4`, with an implied leading
+/// decimal point.
+fn implied_decimal(field: &str) -> Result<f64, Box<dyn Error>> {
+    let field = field.trim();
+    if field.is_empty() {
+        return Ok(0.0);
+    }
+    let (mantissa, exponent) = match field.rfind(['-', '+']) {
+        Some(i) if i > 0 => field.split_at(i),
+        _ => (field, "0"),
+    };
+    let (sign, digits) = match mantissa.strip_prefix('-') {
+        Some(digits) => (-1.0, digits),
+        None => (1.0, mantissa.trim_start_matches('+')),
+    };
+    let mantissa: f64 = format!("0.{}", digits)
+        .parse()
+        .map_err(|_| format!("{} is not an implied decimal", field))?;
+    let exponent: i32 = exponent
+        .parse()
+        .map_err(|_| format!("{} is not an implied decimal", field))?;
+
+    Ok(sign * mantissa * 10f64.powi(exponent))
+}
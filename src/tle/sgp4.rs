@@ -0,0 +1,469 @@
+use std::error::Error;
+use std::f64::consts::PI;
+
+use chrono::{Duration, NaiveDateTime};
+
+use crate::time;
+
+use super::Tle;
+
+/// WGS-72 constants, which the element sets are fitted with
+const EARTH_RADIUS: f64 = 6378.135;
+const MU: f64 = 398600.8;
+const J2: f64 = 0.001082616;
+const J3: f64 = -0.00000253881;
+const J4: f64 = -0.00000165597;
+
+/// WGS-84 ellipsoid, for geodetic coordinates
+const WGS84_A: f64 = 6378.137;
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+const TWO_PI: f64 = 2.0 * PI;
+const X2O3: f64 = 2.0 / 3.0;
+
+/// Position (km) and velocity (km/s) in the TEME frame (true equator, mean equinox).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TemeState {
+    /// Minutes since the epoch of the element set
+    pub minutes: f64,
+    pub position: [f64; 3],
+    pub velocity: [f64; 3],
+}
+
+/// Subsatellite point on the WGS-84 ellipsoid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroundPoint {
+    /// UTC
+    pub datetime: NaiveDateTime,
+    /// Geodetic latitude (deg)
+    pub latitude: f64,
+    /// Longitude (deg, -180 to 180, east positive)
+    pub longitude: f64,
+    /// Altitude above the ellipsoid (km)
+    pub altitude: f64,
+}
+
+/// SGP4 propagator (Vallado et al. 2006 revision, WGS-72, improved mode) of a near-Earth
+/// element set. Deep space orbits (periods of 225 minutes or more, which need SDP4) are
+/// rejected.
+/// # Example
+/// ```
+/// use voyager_client::tle::*;
+///
+/// let tle = Tle::parse(
+///     "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753",
+///     "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667",
+/// )
+/// .unwrap();
+///
+/// let state = tle.sgp4().unwrap().propagate(360.0).unwrap();
+/// assert!((state.position[0] + 7154.031).abs() < 1e-3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sgp4 {
+    epoch: NaiveDateTime,
+    xke: f64,
+    // mean elements at epoch, radians and radians per minute
+    ecco: f64,
+    inclo: f64,
+    nodeo: f64,
+    argpo: f64,
+    mo: f64,
+    no: f64,
+    bstar: f64,
+    // secular and drag coefficients
+    isimp: bool,
+    con41: f64,
+    x1mth2: f64,
+    x7thm1: f64,
+    cosio: f64,
+    sinio: f64,
+    eta: f64,
+    cc1: f64,
+    cc4: f64,
+    cc5: f64,
+    d2: f64,
+    d3: f64,
+    d4: f64,
+    delmo: f64,
+    sinmao: f64,
+    mdot: f64,
+    argpdot: f64,
+    nodedot: f64,
+    nodecf: f64,
+    omgcof: f64,
+    xmcof: f64,
+    xlcof: f64,
+    aycof: f64,
+    t2cof: f64,
+    t3cof: f64,
+    t4cof: f64,
+    t5cof: f64,
+}
+
+impl Sgp4 {
+    /// Initializes the propagator from an element set.
+    pub fn new(tle: &Tle) -> Result<Sgp4, Box<dyn Error>> {
+        let xke = 60.0 / (EARTH_RADIUS.powi(3) / MU).sqrt();
+        let j3oj2 = J3 / J2;
+
+        let ecco = tle.eccentricity;
+        let inclo = tle.inclination.to_radians();
+        let argpo = tle.arg_perigee.to_radians();
+        let mo = tle.mean_anomaly.to_radians();
+        let no_kozai = tle.mean_motion * TWO_PI / 1440.0;
+        if !(0.0..1.0).contains(&ecco) || no_kozai <= 0.0 {
+            return Err("elements are not of a closed orbit".into());
+        }
+
+        // recover the original mean motion and semi-major axis from the Kozai mean motion
+        let eccsq = ecco * ecco;
+        let omeosq = 1.0 - eccsq;
+        let rteosq = omeosq.sqrt();
+        let cosio = inclo.cos();
+        let cosio2 = cosio * cosio;
+        let ak = (xke / no_kozai).powf(X2O3);
+        let d1 = 0.75 * J2 * (3.0 * cosio2 - 1.0) / (rteosq * omeosq);
+        let del = d1 / (ak * ak);
+        let adel = ak * (1.0 - del * del - del * (1.0 / 3.0 + 134.0 * del * del / 81.0));
+        let del = d1 / (adel * adel);
+        let no = no_kozai / (1.0 + del);
+
+        if TWO_PI / no >= 225.0 {
+            return Err("deep space orbits (periods of 225 minutes or more) need SDP4".into());
+        }
+
+        let ao = (xke / no).powf(X2O3);
+        let sinio = inclo.sin();
+        let po = ao * omeosq;
+        let con42 = 1.0 - 5.0 * cosio2;
+        let con41 = -con42 - cosio2 - cosio2;
+        let posq = po * po;
+        let rp = ao * (1.0 - ecco);
+
+        // perigees under 220 km use a simplified drag model
+        let isimp = rp < 220.0 / EARTH_RADIUS + 1.0;
+
+        let mut sfour = 78.0 / EARTH_RADIUS + 1.0;
+        let mut qzms24 = ((120.0 - 78.0) / EARTH_RADIUS).powi(4);
+        let perige = (rp - 1.0) * EARTH_RADIUS;
+        if perige < 156.0 {
+            sfour = if perige < 98.0 { 20.0 } else { perige - 78.0 };
+            qzms24 = ((120.0 - sfour) / EARTH_RADIUS).powi(4);
+            sfour = sfour / EARTH_RADIUS + 1.0;
+        }
+
+        let pinvsq = 1.0 / posq;
+        let tsi = 1.0 / (ao - sfour);
+        let eta = ao * ecco * tsi;
+        let etasq = eta * eta;
+        let eeta = ecco * eta;
+        let psisq = (1.0 - etasq).abs();
+        let coef = qzms24 * tsi.powi(4);
+        let coef1 = coef / psisq.powf(3.5);
+        let cc2 = coef1
+            * no
+            * (ao * (1.0 + 1.5 * etasq + eeta * (4.0 + etasq))
+                + 0.375 * J2 * tsi / psisq * con41 * (8.0 + 3.0 * etasq * (8.0 + etasq)));
+        let cc1 = tle.bstar * cc2;
+        let cc3 = if ecco > 1.0e-4 {
+            -2.0 * coef * tsi * j3oj2 * no * sinio / ecco
+        } else {
+            0.0
+        };
+        let x1mth2 = 1.0 - cosio2;
+        let cc4 = 2.0
+            * no
+            * coef1
+            * ao
+            * omeosq
+            * (eta * (2.0 + 0.5 * etasq) + ecco * (0.5 + 2.0 * etasq)
+                - J2 * tsi / (ao * psisq)
+                    * (-3.0 * con41 * (1.0 - 2.0 * eeta + etasq * (1.5 - 0.5 * eeta))
+                        + 0.75
+                            * x1mth2
+                            * (2.0 * etasq - eeta * (1.0 + etasq))
+                            * (2.0 * argpo).cos()));
+        let cc5 = 2.0 * coef1 * ao * omeosq * (1.0 + 2.75 * (etasq + eeta) + eeta * etasq);
+
+        let cosio4 = cosio2 * cosio2;
+        let temp1 = 1.5 * J2 * pinvsq * no;
+        let temp2 = 0.5 * temp1 * J2 * pinvsq;
+        let temp3 = -0.46875 * J4 * pinvsq * pinvsq * no;
+        let mdot = no
+            + 0.5 * temp1 * rteosq * con41
+            + 0.0625 * temp2 * rteosq * (13.0 - 78.0 * cosio2 + 137.0 * cosio4);
+        let argpdot = -0.5 * temp1 * con42
+            + 0.0625 * temp2 * (7.0 - 114.0 * cosio2 + 395.0 * cosio4)
+            + temp3 * (3.0 - 36.0 * cosio2 + 49.0 * cosio4);
+        let xhdot1 = -temp1 * cosio;
+        let nodedot = xhdot1
+            + (0.5 * temp2 * (4.0 - 19.0 * cosio2) + 2.0 * temp3 * (3.0 - 7.0 * cosio2)) * cosio;
+
+        let xmcof = if ecco > 1.0e-4 {
+            -X2O3 * coef * tle.bstar / eeta
+        } else {
+            0.0
+        };
+        // avoids a division by zero for inclinations of 180 degrees
+        let xlcof_den = if (cosio + 1.0).abs() > 1.5e-12 {
+            1.0 + cosio
+        } else {
+            1.5e-12
+        };
+
+        let (mut d2, mut d3, mut d4) = (0.0, 0.0, 0.0);
+        let (mut t3cof, mut t4cof, mut t5cof) = (0.0, 0.0, 0.0);
+        if !isimp {
+            let cc1sq = cc1 * cc1;
+            d2 = 4.0 * ao * tsi * cc1sq;
+            let temp = d2 * tsi * cc1 / 3.0;
+            d3 = (17.0 * ao + sfour) * temp;
+            d4 = 0.5 * temp * ao * tsi * (221.0 * ao + 31.0 * sfour) * cc1;
+            t3cof = d2 + 2.0 * cc1sq;
+            t4cof = 0.25 * (3.0 * d3 + cc1 * (12.0 * d2 + 10.0 * cc1sq));
+            t5cof = 0.2
+                * (3.0 * d4 + 12.0 * cc1 * d3 + 6.0 * d2 * d2 + 15.0 * cc1sq * (2.0 * d2 + cc1sq));
+        }
+
+        Ok(Sgp4 {
+            epoch: tle.epoch,
+            xke,
+            ecco,
+            inclo,
+            nodeo: tle.raan.to_radians(),
+            argpo,
+            mo,
+            no,
+            bstar: tle.bstar,
+            isimp,
+            con41,
+            x1mth2,
+            x7thm1: 7.0 * cosio2 - 1.0,
+            cosio,
+            sinio,
+            eta,
+            cc1,
+            cc4,
+            cc5,
+            d2,
+            d3,
+            d4,
+            delmo: (1.0 + eta * mo.cos()).powi(3),
+            sinmao: mo.sin(),
+            mdot,
+            argpdot,
+            nodedot,
+            nodecf: 3.5 * omeosq * xhdot1 * cc1,
+            omgcof: tle.bstar * cc3 * argpo.cos(),
+            xmcof,
+            xlcof: -0.25 * j3oj2 * sinio * (3.0 + 5.0 * cosio) / xlcof_den,
+            aycof: -0.5 * j3oj2 * sinio,
+            t2cof: 1.5 * cc1,
+            t3cof,
+            t4cof,
+            t5cof,
+        })
+    }
+
+    /// Epoch of the element set (UTC)
+    pub fn epoch(&self) -> NaiveDateTime {
+        self.epoch
+    }
+
+    /// State at the given minutes since the epoch (negative to go back).
+    pub fn propagate(&self, minutes: f64) -> Result<TemeState, Box<dyn Error>> {
+        let t = minutes;
+
+        // secular gravity and atmospheric drag
+        let xmdf = self.mo + self.mdot * t;
+        let argpdf = self.argpo + self.argpdot * t;
+        let nodedf = self.nodeo + self.nodedot * t;
+        let t2 = t * t;
+        let mut argpm = argpdf;
+        let mut mm = xmdf;
+        let mut nodem = nodedf + self.nodecf * t2;
+        let mut tempa = 1.0 - self.cc1 * t;
+        let mut tempe = self.bstar * self.cc4 * t;
+        let mut templ = self.t2cof * t2;
+
+        if !self.isimp {
+            let delomg = self.omgcof * t;
+            let delm = self.xmcof * ((1.0 + self.eta * xmdf.cos()).powi(3) - self.delmo);
+            mm = xmdf + delomg + delm;
+            argpm = argpdf - delomg - delm;
+            let t3 = t2 * t;
+            let t4 = t3 * t;
+            tempa -= self.d2 * t2 + self.d3 * t3 + self.d4 * t4;
+            tempe += self.bstar * self.cc5 * (mm.sin() - self.sinmao);
+            templ += self.t3cof * t3 + t4 * (self.t4cof + t * self.t5cof);
+        }
+
+        let am = (self.xke / self.no).powf(X2O3) * tempa * tempa;
+        let nm = self.xke / am.powf(1.5);
+        let mut em = self.ecco - tempe;
+        if !(-0.001..1.0).contains(&em) {
+            return Err(format!("eccentricity {} is out of range at {} minutes", em, t).into());
+        }
+        em = em.max(1.0e-6);
+
+        mm += self.no * templ;
+        let xlm = mm + argpm + nodem;
+        nodem = nodem.rem_euclid(TWO_PI);
+        argpm = argpm.rem_euclid(TWO_PI);
+        mm = (xlm.rem_euclid(TWO_PI) - argpm - nodem).rem_euclid(TWO_PI);
+
+        // long period periodics
+        let axnl = em * argpm.cos();
+        let temp = 1.0 / (am * (1.0 - em * em));
+        let aynl = em * argpm.sin() + temp * self.aycof;
+        let xl = mm + argpm + nodem + temp * self.xlcof * axnl;
+
+        // Kepler's equation
+        let u = (xl - nodem).rem_euclid(TWO_PI);
+        let mut eo1 = u;
+        let (mut sineo1, mut coseo1) = (0.0, 0.0);
+        for _ in 0..10 {
+            sineo1 = eo1.sin();
+            coseo1 = eo1.cos();
+            let step =
+                (u - aynl * coseo1 + axnl * sineo1 - eo1) / (1.0 - coseo1 * axnl - sineo1 * aynl);
+            eo1 += step.clamp(-0.95, 0.95);
+            if step.abs() < 1.0e-12 {
+                break;
+            }
+        }
+
+        // short period periodics
+        let ecose = axnl * coseo1 + aynl * sineo1;
+        let esine = axnl * sineo1 - aynl * coseo1;
+        let el2 = axnl * axnl + aynl * aynl;
+        let pl = am * (1.0 - el2);
+        if pl < 0.0 {
+            return Err(format!("semi-latus rectum is negative at {} minutes", t).into());
+        }
+
+        let rl = am * (1.0 - ecose);
+        let rdotl = am.sqrt() * esine / rl;
+        let rvdotl = pl.sqrt() / rl;
+        let betal = (1.0 - el2).sqrt();
+        let temp = esine / (1.0 + betal);
+        let sinu = am / rl * (sineo1 - aynl - axnl * temp);
+        let cosu = am / rl * (coseo1 - axnl + aynl * temp);
+        let su = sinu.atan2(cosu);
+        let sin2u = (cosu + cosu) * sinu;
+        let cos2u = 1.0 - 2.0 * sinu * sinu;
+        let temp = 1.0 / pl;
+        let temp1 = 0.5 * J2 * temp;
+        let temp2 = temp1 * temp;
+
+        let mrt = rl * (1.0 - 1.5 * temp2 * betal * self.con41) + 0.5 * temp1 * self.x1mth2 * cos2u;
+        let su = su - 0.25 * temp2 * self.x7thm1 * sin2u;
+        let xnode = nodem + 1.5 * temp2 * self.cosio * sin2u;
+        let xinc = self.inclo + 1.5 * temp2 * self.cosio * self.sinio * cos2u;
+        let mvt = rdotl - nm * temp1 * self.x1mth2 * sin2u / self.xke;
+        let rvdot = rvdotl + nm * temp1 * (self.x1mth2 * cos2u + 1.5 * self.con41) / self.xke;
+
+        if mrt < 1.0 {
+            return Err(format!("satellite has decayed at {} minutes", t).into());
+        }
+
+        // orientation vectors
+        let (sinsu, cossu) = su.sin_cos();
+        let (snod, cnod) = xnode.sin_cos();
+        let (sini, cosi) = xinc.sin_cos();
+        let xmx = -snod * cosi;
+        let xmy = cnod * cosi;
+        let ux = [
+            xmx * sinsu + cnod * cossu,
+            xmy * sinsu + snod * cossu,
+            sini * sinsu,
+        ];
+        let vx = [
+            xmx * cossu - cnod * sinsu,
+            xmy * cossu - snod * sinsu,
+            sini * cossu,
+        ];
+
+        let vkmpersec = EARTH_RADIUS * self.xke / 60.0;
+        let position = ux.map(|u| mrt * u * EARTH_RADIUS);
+        let velocity = [0, 1, 2].map(|i| (mvt * ux[i] + rvdot * vx[i]) * vkmpersec);
+
+        Ok(TemeState {
+            minutes,
+            position,
+            velocity,
+        })
+    }
+
+    /// State at a time (UTC)
+    pub fn propagate_to(&self, datetime: NaiveDateTime) -> Result<TemeState, Box<dyn Error>> {
+        let minutes = (datetime - self.epoch)
+            .num_microseconds()
+            .ok_or("time is out of range")? as f64
+            / 60_000_000.0;
+        self.propagate(minutes)
+    }
+
+    /// Subsatellite point at a time (UTC)
+    pub fn ground_point(&self, datetime: NaiveDateTime) -> Result<GroundPoint, Box<dyn Error>> {
+        let state = self.propagate_to(datetime)?;
+        Ok(ground_point(datetime, &state.position))
+    }
+
+    /// Subsatellite points from `start` to `end` (inclusive), every `step`.
+    pub fn ground_track(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        step: Duration,
+    ) -> Result<Vec<GroundPoint>, Box<dyn Error>> {
+        if step <= Duration::zero() {
+            return Err("step must be positive".into());
+        }
+
+        let mut track = Vec::new();
+        let mut datetime = start;
+        while datetime <= end {
+            track.push(self.ground_point(datetime)?);
+            datetime += step;
+        }
+
+        Ok(track)
+    }
+}
+
+/// Greenwich mean sidereal time (radians) at a Julian Date (UT1, UTC is close enough here).
+pub fn gmst(jd: f64) -> f64 {
+    let tut1 = (jd - 2451545.0) / 36525.0;
+    let seconds = -6.2e-6 * tut1.powi(3)
+        + 0.093104 * tut1 * tut1
+        + (876600.0 * 3600.0 + 8640184.812866) * tut1
+        + 67310.54841;
+    (seconds / 240.0).to_radians().rem_euclid(TWO_PI)
+}
+
+/// Geodetic coordinates of a TEME position (km) at a time (UTC). Polar motion is ignored.
+pub fn ground_point(datetime: NaiveDateTime, position: &[f64; 3]) -> GroundPoint {
+    let (sin_g, cos_g) = gmst(time::julian_date(datetime)).sin_cos();
+    let x = position[0] * cos_g + position[1] * sin_g;
+    let y = -position[0] * sin_g + position[1] * cos_g;
+    let z = position[2];
+
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let p = (x * x + y * y).sqrt();
+    let mut latitude = z.atan2(p * (1.0 - e2));
+    let mut altitude = 0.0;
+    for _ in 0..5 {
+        let n = WGS84_A / (1.0 - e2 * latitude.sin().powi(2)).sqrt();
+        altitude = p / latitude.cos() - n;
+        latitude = z.atan2(p * (1.0 - e2 * n / (n + altitude)));
+    }
+
+    GroundPoint {
+        datetime,
+        latitude: latitude.to_degrees(),
+        longitude: y.atan2(x).to_degrees(),
+        altitude,
+    }
+}
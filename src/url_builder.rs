@@ -0,0 +1,70 @@
+use std::fmt::Display;
+
+/// Builds request urls for the clients, percent-encoding every query key and value so
+/// designations with spaces or search terms with `&`, `=` or `#` reach the API as written.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct UrlBuilder {
+    base: String,
+    query: Vec<String>,
+}
+
+impl UrlBuilder {
+    /// Starts a url from a base without a query string
+    pub(crate) fn new(base: &str) -> Self {
+        UrlBuilder {
+            base: String::from(base),
+            query: Vec::new(),
+        }
+    }
+
+    /// Appends `key=value`
+    pub(crate) fn param<T: Display>(&mut self, key: &str, value: T) -> &mut Self {
+        self.query
+            .push(format!("{}={}", encode(key), encode(&value.to_string())));
+        self
+    }
+
+    /// Appends a bare value without a key, as the Tech Transfer search term
+    pub(crate) fn term(&mut self, term: &str) -> &mut Self {
+        self.query.push(encode(term));
+        self
+    }
+
+    pub(crate) fn build(&self) -> String {
+        if self.query.is_empty() {
+            self.base.clone()
+        } else {
+            format!("{}?{}", self.base, self.query.join("&"))
+        }
+    }
+}
+
+/// Percent-encodes a query component. Unreserved characters and the delimiters that carry
+/// no meaning inside a value (`!$'()*,/:;@`) are kept, so urls stay readable.
+pub(crate) fn encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z'
+            | b'a'..=b'z'
+            | b'0'..=b'9'
+            | b'-'
+            | b'.'
+            | b'_'
+            | b'~'
+            | b'!'
+            | b'$'
+            | b'\''
+            | b'('
+            | b')'
+            | b'*'
+            | b','
+            | b'/'
+            | b':'
+            | b';'
+            | b'@' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
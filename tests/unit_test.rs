@@ -143,7 +143,7 @@ mod test {
         use voyager_client::jpl::*;
 
         let base = MissionDesign::new();
-        base.query(QueryType::DES, "2012%20TC4").unwrap();
+        base.query(QueryType::DES, "2012 TC4").unwrap();
     }
 
     #[test]
@@ -163,7 +163,7 @@ mod test {
         use voyager_client::jpl::*;
 
         let mut base = MissionDesignMap::new();
-        base.designation("2012%20TC4");
+        base.designation("2012 TC4");
         base.mjd(58490);
         base.span(3652);
         base.tof(10, 36);
@@ -182,4 +182,1502 @@ mod test {
 
         base.query(start, end).unwrap();
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn try_fireball_records() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let res = into_response(
+            r#"{"signature":{"version":"1.0","source":"NASA/JPL Fireball Data API"},"count":"3",
+            "fields":["date","energy","impact-e","lat","lat-dir","lon","lon-dir","alt","vel"],
+            "data":[["2015-10-13 12:23:08","3.6","0.12","3.1","S","18.4","W","41.2","17.2"],
+                    ["2015-02-01 01:02:03","70.2","1.9",null,null,null,null,null,null],
+                    ["2016-04-28 11:11:11","4.1","0.14","12.0","N","1.5","E","30.0",null]]}"#,
+        )
+        .unwrap();
+
+        let fireballs = Fireball::from_response(&res).unwrap();
+        assert_eq!(fireballs.len(), 3);
+        assert_eq!(fireballs[0].lat, Some(-3.1));
+        assert_eq!(fireballs[0].lon, Some(-18.4));
+        assert!(!fireballs[1].is_located());
+        assert_eq!(fireballs[2].vel, None);
+
+        let years = fireball_energy_by_year(&fireballs);
+        assert!((years[&2015] - 2.02).abs() < 1e-9);
+        assert!((years[&2016] - 0.14).abs() < 1e-9);
+
+        let strongest = strongest_fireballs(&fireballs, 2);
+        assert_eq!(strongest[0].impact_e, 1.9);
+        assert_eq!(strongest[1].impact_e, 0.14);
+
+        let geojson = fireballs_to_geojson(&fireballs);
+        let features = geojson["features"].as_array().unwrap();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0]["geometry"]["coordinates"][0], -18.4);
+    }
+
+    #[test]
+    fn try_fireball_filters() {
+        use voyager_client::jpl::*;
+
+        let mut base = FireballClient::new();
+        assert_eq!(
+            base.url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/fireball.api"
+        );
+
+        base.date_min("2010-01-01");
+        base.impact_e_min(1.0);
+        base.req_loc(true);
+        base.sort(FireballSort::ImpactE, true);
+        assert_eq!(
+            base.url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/fireball.api?date-min=2010-01-01&impact-e-min=1&req-loc=true&sort=-impact-e"
+        );
+
+        base.impact_e_max(0.5);
+        assert!(base.url().is_err());
+        base.impact_e_max(5.0);
+
+        base.vel_comp(true);
+        assert!(base.url().is_err());
+        base.req_vel(true);
+        assert!(base.url().is_ok());
+
+        base.date_max("2009-12-31T23:59:59");
+        assert!(base.url().is_err());
+        base.date_max("2022-13-01");
+        assert!(base.url().is_err());
+    }
+
+    #[test]
+    fn try_sentry_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = SentryClient::new();
+        base.ip_min(1e-6);
+        base.h_max(22.0);
+        assert_eq!(
+            base.summary_url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/sentry.api?ip-min=0.000001&h-max=22"
+        );
+        assert_eq!(
+            base.object_url(&SentryTarget::Spk(2029075)),
+            "https://ssd-api.jpl.nasa.gov/sentry.api?spk=2029075"
+        );
+        base.ip_min(2.0);
+        assert!(base.summary_url().is_err());
+
+        let summary = into_response(
+            r#"{"signature":{"source":"NASA/JPL Sentry Data API","version":"2.0"},"count":"1",
+            "data":[{"des":"29075","fullname":"29075 (1950 DA)","ip":"0.0002","ps_cum":"-0.93",
+            "ps_max":"-0.93","ts_max":null,"n_imp":1,"range":"2880-2880","last_obs":"2021-02-23",
+            "h":"17.9","diameter":"1.3","v_inf":"14.1"}]}"#,
+        )
+        .unwrap();
+        let summaries = SentrySummary::from_response(&summary).unwrap();
+        assert_eq!(summaries[0].des, "29075");
+        assert_eq!(summaries[0].ps_max, -0.93);
+        assert_eq!(summaries[0].ts_max, None);
+        assert_eq!(summaries[0].n_imp, 1);
+        let incomplete = into_response(
+            r#"{"count":"1","data":[{"des":"29075","ip":"0.0002","ps_cum":"-0.93","ps_max":"-0.93"}]}"#,
+        )
+        .unwrap();
+        assert!(SentrySummary::from_response(&incomplete).is_err());
+
+        let object = into_response(
+            r#"{"signature":{"source":"NASA/JPL Sentry Data API","version":"2.0"},
+            "summary":{"des":"2000 SG344","fullname":"(2000 SG344)","ip":"0.0027","ps_cum":"-2.77",
+            "ps_max":"-2.79","ts_max":"0","n_imp":"2","h":"24.8","diameter":"0.037","energy":"1.3",
+            "v_inf":"1.36","v_imp":"11.3","method":"IOBS"},
+            "data":[{"date":"2069-09-16.11","ip":"0.0011","ps":"-2.79","ts":"0","energy":"1.3",
+            "dist":"0.46","width":"1.03","sigma_lov":"-1.92","sigma_imp":"0.01"},
+            {"date":"2070-09-16.60","ip":"0.0009","ps":"-2.89","ts":"0"}]}"#,
+        )
+        .unwrap();
+        let object = SentryObject::from_response(&object).unwrap();
+        assert_eq!(object.ts_max, Some(0));
+        assert_eq!(object.virtual_impactors.len(), 2);
+        assert_eq!(object.virtual_impactors[0].ps, -2.79);
+        assert_eq!(object.virtual_impactors[1].dist, None);
+
+        let removed = into_response(
+            r#"{"signature":{"version":"2.0"},"count":"1","data":[{"des":"2022 AE1","removed":"2022-01-09 14:16:02"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            SentryRemoved::from_response(&removed).unwrap()[0].des,
+            "2022 AE1"
+        );
+
+        let error = into_response(r#"{"error":"specified object removed","removed":"2022-01-09"}"#)
+            .unwrap();
+        assert!(SentryObject::from_response(&error).is_err());
+    }
+
+    #[test]
+    fn try_sbdb_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = SbdbClient::new();
+        base.phys_par(true);
+        base.cov(SbdbCovariance::Mat);
+        assert_eq!(
+            base.url(QueryType::SPK, "2000433"),
+            "https://ssd-api.jpl.nasa.gov/sbdb.api?spk=2000433&phys-par=1&cov=mat"
+        );
+
+        let res = into_response(
+            r#"{"signature":{"source":"NASA/JPL Small-Body Database (SBDB) API","version":"1.3"},
+            "object":{"des":"433","spkid":"2000433","fullname":"433 Eros (A898 PA)","kind":"an",
+            "orbit_class":{"code":"AMO","name":"Amor"},"neo":true,"pha":false,
+            "des_alt":[{"des":"1898 DQ"},{"des":"1956 PC"}]},
+            "orbit":{"epoch":"2459600.5","elements":[
+                {"name":"e","value":".2228359407071628"},{"name":"a","value":"1.458120998474684"},
+                {"name":"q","value":"1.133195320314996"},{"name":"i","value":"10.82846651399785"},
+                {"name":"om","value":"304.2701025753316"},{"name":"w","value":"178.9297536744151"},
+                {"name":"ma","value":"310.5543277370992"},{"name":"tp","value":"2459650.76"}],
+                "covariance":{"epoch":"2459600.5","labels":["e","q"],"data":[["1e-18","2e-19"],["2e-19","3e-18"]]}},
+            "phys_par":[{"name":"diameter","value":"16.84","sigma":".06","units":"km"}],
+            "ca_data":[{"cd":"1900-Dec-27 01:29","jd":"2415380.56","body":"Earth","dist":".314","v_rel":"5.9"}],
+            "discovery":{"date":"1898-Aug-13","location":"Berlin","who":"G. Witt"}}"#,
+        )
+        .unwrap();
+
+        let eros = SbdbObject::from_response(&res).unwrap();
+        assert_eq!(eros.des, "433");
+        assert!(eros.neo && !eros.pha);
+        assert_eq!(eros.orbit_class.as_deref(), Some("AMO"));
+        assert_eq!(eros.orbit.epoch, 2459600.5);
+        assert!((eros.orbit.e - 0.2228359407071628).abs() < 1e-15);
+        assert_eq!(eros.orbit.a, Some(1.458120998474684));
+        assert_eq!(eros.phys_par[0].units.as_deref(), Some("km"));
+        assert_eq!(eros.close_approaches[0].body.as_deref(), Some("Earth"));
+        assert_eq!(eros.discovery.unwrap().who.as_deref(), Some("G. Witt"));
+        assert_eq!(eros.covariance.unwrap().data[1][1], 3e-18);
+        assert_eq!(eros.alt_des, vec!["1898 DQ", "1956 PC"]);
+
+        // cov=src: the square-root covariance is one flat upper triangle
+        let src = into_response(
+            r#"{"signature":{"version":"1.3"},"object":{"des":"433"},
+            "orbit":{"epoch":"2459600.5","elements":[
+                {"name":"e","value":".2228359407071628"},{"name":"q","value":"1.133195320314996"},
+                {"name":"i","value":"10.82846651399785"},{"name":"om","value":"304.2701025753316"},
+                {"name":"w","value":"178.9297536744151"},{"name":"tp","value":"2459650.76"}],
+                "covariance":{"epoch":"2459600.5","labels":["e","q","tp"],
+                "data":["1e-9","2e-10","3e-9","4e-11","5e-10","6e-8"]}}}"#,
+        )
+        .unwrap();
+        let covariance = SbdbObject::from_response(&src).unwrap().covariance.unwrap();
+        assert!(covariance.data.is_empty());
+        assert_eq!(covariance.src.len(), 6);
+        assert_eq!(covariance.src[5], 6e-8);
+
+        let ambiguous = into_response(
+            r#"{"code":"300","message":"specified search string matched more than one object",
+            "list":[{"pdes":"2003 CP20","name":"Atira"},{"pdes":"163693","name":null}]}"#,
+        )
+        .unwrap();
+        assert!(SbdbObject::from_response(&ambiguous).is_err());
+    }
+
+    #[test]
+    fn try_orbit_propagation() {
+        use serde_json::Value as JsonValue;
+        use voyager_client::jpl::OrbitElements;
+        use voyager_client::orbit::*;
+
+        let fixtures: JsonValue =
+            serde_json::from_str(include_str!("fixtures/orbit.json")).unwrap();
+        let number = |case: &JsonValue, key: &str| case[key].as_f64().unwrap();
+
+        for case in fixtures["kepler"].as_array().unwrap() {
+            let anomaly = solve_kepler(number(case, "m"), number(case, "e"));
+            assert!(
+                (anomaly - number(case, "anomaly")).abs() < 1e-5,
+                "{}",
+                case["source"]
+            );
+        }
+        for case in fixtures["hyperbolic"].as_array().unwrap() {
+            let anomaly = solve_hyperbolic_kepler(number(case, "m"), number(case, "e"));
+            assert!(
+                (anomaly - number(case, "anomaly")).abs() < 1e-4,
+                "{}",
+                case["source"]
+            );
+        }
+
+        // Mean elements agree with the JPL ephemeris to a few 10^-4 au
+        let earth = &fixtures["earth"];
+        let state = state_at(&earth_elements(), number(earth, "jd")).unwrap();
+        for (axis, expected) in earth["position"].as_array().unwrap().iter().enumerate() {
+            assert!((state.position[axis] - expected.as_f64().unwrap()).abs() < 5e-4);
+        }
+
+        // An elliptic orbit returns to its starting point after one period
+        let elements = OrbitElements {
+            epoch: J2000,
+            e: 0.2228,
+            a: Some(1.4581),
+            q: 1.4581 * (1.0 - 0.2228),
+            i: 10.83,
+            om: 304.27,
+            w: 178.93,
+            ma: Some(310.55),
+            tp: None,
+        };
+        let period = 2.0 * std::f64::consts::PI / (GAUSS_K / 1.4581f64.powf(1.5));
+        let start = state_at(&elements, J2000).unwrap();
+        let end = state_at(&elements, J2000 + period).unwrap();
+        assert!(start.distance_to(&end) < 1e-9);
+        // vis-viva
+        let speed = start.velocity.iter().map(|v| v * v).sum::<f64>().sqrt();
+        let expected = (GM_SUN * (2.0 / start.distance() - 1.0 / 1.4581)).sqrt();
+        assert!((speed - expected).abs() < 1e-12);
+
+        // Parabolic and hyperbolic orbits are at q at perihelion, and close to each other nearby
+        let mut comet = OrbitElements {
+            epoch: J2000,
+            e: 1.0,
+            a: None,
+            q: 0.5,
+            i: 40.0,
+            om: 10.0,
+            w: 20.0,
+            ma: None,
+            tp: Some(J2000),
+        };
+        assert!((state_at(&comet, J2000).unwrap().distance() - 0.5).abs() < 1e-12);
+        let parabolic = state_at(&comet, J2000 + 30.0).unwrap();
+        comet.e = 1.000001;
+        assert!((state_at(&comet, J2000).unwrap().distance() - 0.5).abs() < 1e-12);
+        let hyperbolic = state_at(&comet, J2000 + 30.0).unwrap();
+        assert!(parabolic.distance_to(&hyperbolic) < 1e-5);
+
+        // Along the parabola (Barker's equation) the speed is the escape speed and the
+        // angular momentum stays sqrt(2 GM q)
+        comet.e = 1.0;
+        for days in [-200.0, -30.0, 30.0, 200.0] {
+            let state = state_at(&comet, J2000 + days).unwrap();
+            let speed = state.velocity.iter().map(|v| v * v).sum::<f64>().sqrt();
+            let escape = (2.0 * GM_SUN / state.distance()).sqrt();
+            assert!((speed - escape).abs() < 1e-12, "{} days", days);
+            let [x, y, z] = state.position;
+            let [vx, vy, vz] = state.velocity;
+            let momentum = [y * vz - z * vy, z * vx - x * vz, x * vy - y * vx];
+            let momentum = momentum.iter().map(|h| h * h).sum::<f64>().sqrt();
+            assert!((momentum - (2.0 * GM_SUN * 0.5f64).sqrt()).abs() < 1e-12);
+            assert!(state.distance() > 0.5);
+        }
+
+        // One sidereal year from J2000, the Earth is back where Horizons puts it at J2000
+        for year in [-365.256363, 365.256363] {
+            let state = state_at(&earth_elements(), number(earth, "jd") + year).unwrap();
+            for (axis, expected) in earth["position"].as_array().unwrap().iter().enumerate() {
+                assert!((state.position[axis] - expected.as_f64().unwrap()).abs() < 1e-3);
+            }
+        }
+
+        let distances =
+            earth_distances(&elements, &jd_range(J2000, J2000 + 10.0, 5.0).unwrap()).unwrap();
+        assert_eq!(distances.len(), 3);
+        for &(jd, distance) in &distances {
+            let body = state_at(&elements, jd).unwrap();
+            let earth = state_at(&earth_elements(), jd).unwrap();
+            assert!((distance - body.distance_to(&earth)).abs() < 1e-15);
+        }
+        assert!(jd_range(J2000, J2000 + 10.0, 0.0).is_err());
+        assert!(jd_range(J2000, J2000 + 10.0, -1.0).is_err());
+        assert!(jd_range(J2000, J2000 + 10.0, f64::NAN).is_err());
+        assert!(jd_range(J2000, J2000 + 10.0, 1e-9).is_err());
+        assert!(jd_range(J2000 + 10.0, J2000, 1.0).unwrap().is_empty());
+
+        let neo: JsonValue = serde_json::from_str(
+            r#"{"epoch_osculation":"2459600.5","eccentricity":".2228","semi_major_axis":"1.4581",
+            "inclination":"10.83","ascending_node_longitude":"304.27","perihelion_distance":"1.1332",
+            "perihelion_argument":"178.93","mean_anomaly":"310.55","perihelion_time":"2459650.76"}"#,
+        )
+        .unwrap();
+        let elements = elements_from_neo(&neo).unwrap();
+        assert_eq!(elements.q, 1.1332);
+        assert_eq!(elements.ma, Some(310.55));
+    }
+
+    #[test]
+    fn try_scout_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = ScoutClient::new();
+        assert!(base.plan_url().is_err());
+        base.eph_stop("2022-01-02");
+        assert!(base.detail_url("P21Xyz1").is_err());
+        base.eph_start("2022-01-01");
+        base.obs_code("568");
+        base.orbits(true);
+        assert_eq!(
+            base.detail_url("P21Xyz1").unwrap(),
+            "https://ssd-api.jpl.nasa.gov/scout.api?tdes=P21Xyz1&orbits=1&eph-start=2022-01-01&eph-stop=2022-01-02&obs-code=568"
+        );
+
+        let summary = into_response(
+            r#"{"signature":{"source":"NASA/JPL Scout API","version":"1.3"},"count":"3","data":[
+            {"objectName":"A","rating":"1","neoScore":"99","nObs":"4","H":"24.1","Vmag":"21.3"},
+            {"objectName":"B","rating":null,"neoScore":"100"},
+            {"objectName":"C","rating":"3","neoScore":"12","caDist":"3.2"}]}"#,
+        )
+        .unwrap();
+        let objects = ScoutObject::from_response(&summary).unwrap();
+        assert_eq!(objects[0].n_obs, Some(4));
+        let order: Vec<&str> = prioritize_scout(&objects)
+            .iter()
+            .map(|object| object.object_name.as_str())
+            .collect();
+        assert_eq!(order, vec!["C", "A", "B"]);
+
+        let detail = into_response(
+            r#"{"signature":{"source":"NASA/JPL Scout API","version":"1.3"},"objectName":"A","rating":"1",
+            "orbits":{"count":"1","fields":["idx","epoch","ec","qr","tp","om","w","in","H","moid"],
+            "data":[[0,"2459580.5","0.41","0.93","2459600.1","120.1","45.3","7.2","24.1","0.01"]]},
+            "eph":[{"time":"2022-01-01 00:00","median":{"ra":"180.5","dec":"1.2","vmag":"21.3"},"sigma-pos":"12.5"}]}"#,
+        )
+        .unwrap();
+        let detail = ScoutDetail::from_response(&detail).unwrap();
+        assert_eq!(detail.summary.unwrap().rating, Some(1));
+        assert_eq!(detail.orbits[0].inc, 7.2);
+        assert_eq!(detail.orbits[0].elements().tp, Some(2459600.1));
+        assert_eq!(detail.ephemeris[0].ra, Some(180.5));
+        assert_eq!(detail.ephemeris[0].sigma_pos, Some(12.5));
+    }
+
+    #[test]
+    fn try_nhats_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = NhatsClient::new();
+        base.dv(6);
+        base.dur(360);
+        base.launch("2025-2030");
+        assert_eq!(
+            base.object_url(QueryType::DES, "2000 SG344").unwrap(),
+            "https://ssd-api.jpl.nasa.gov/nhats.api?des=2000%20SG344&dv=6&dur=360&launch=2025-2030"
+        );
+        assert!(base.object_url(QueryType::SSTR, "atira").is_err());
+        base.dur(365);
+        assert!(base.summary_url().is_err());
+
+        let summary = into_response(
+            r#"{"signature":{"source":"NASA/JPL NHATS API","version":"1.0"},"count":"2","data":[
+            {"des":"2000 SG344","h":"24.7","occ":"3","n_via_traj":"1187","min_dv":{"dv":"3.556","dur":"354"},
+             "min_dur":{"dv":"11.7","dur":"42"},"obs_mag":"21.9"},
+            {"des":"2017 SV19","min_dv":{"dv":"4.9","dur":"362"},"min_dur":{"dv":"11.9","dur":"66"}}]}"#,
+        )
+        .unwrap();
+        let objects = NhatsObject::from_response(&summary).unwrap();
+        assert_eq!(objects[0].min_dv, Some((3.556, 354.0)));
+        assert_eq!(objects[0].occ, Some(3));
+        assert_eq!(lowest_delta_v(&objects, 1)[0].des, "2000 SG344");
+
+        let ranked = into_response(
+            r#"{"signature":{"version":"1.0"},"count":"2",
+            "fields":["name","class","H","condition_code","date0","MJD0","datef","MJDF","tof","c3_dep","vinf_arr","dv_tot"],
+            "data":[["(2000 SG344)","ATE","24.7","3","2028-04-26","61887","2028-10-05","62049","162","0.5","1.4","3.6"],
+            ["2012 TC4","APO","26.7","0","2034-10-05","64230","2035-01-13","64330","100","6.1","2.6","6.4"]]}"#,
+        )
+        .unwrap();
+        let targets = AccessibleTarget::from_response(&ranked).unwrap();
+        let joined = join_accessible(&objects, &targets);
+        assert_eq!(joined.len(), 1);
+        assert_eq!(joined[0].0.des, "2000 SG344");
+        assert_eq!(joined[0].1.mission.dv_tot, Some(3.6));
+
+        let detail = into_response(
+            r#"{"signature":{"version":"1.0"},"des":"2000 SG344","h":"24.7",
+            "min_dv":{"dv":"3.556","dur":"354"},
+            "min_dv_traj":{"launch":"2028-04-26","dv_total":"3.556","dur_total":"354","dur_out":"162",
+            "dur_at":"8","dur_ret":"184","c3":"0.5"}}"#,
+        )
+        .unwrap();
+        let detail = NhatsDetail::from_response(&detail).unwrap();
+        let trajectory = detail.min_dv_traj.unwrap();
+        assert_eq!(trajectory.launch.as_deref(), Some("2028-04-26"));
+        assert_eq!(trajectory.dur_at, Some(8.0));
+        assert!(detail.min_dur_traj.is_none());
+    }
+
+    #[test]
+    fn try_horizons_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = HorizonsClient::new();
+        assert!(base.url().is_err());
+        base.command("499");
+        base.center("500@10");
+        base.start_time("2022-01-01");
+        base.stop_time("2022-01-02");
+        base.step_size("1d");
+        base.ephem_type(EphemType::Vectors);
+        base.quantities("1,9");
+        assert!(base.url().is_err());
+        base.ephem_type(EphemType::Observer);
+        assert_eq!(
+            base.url().unwrap(),
+            "https://ssd.jpl.nasa.gov/api/horizons.api?format=json&COMMAND='499'&OBJ_DATA='NO'&MAKE_EPHEM='YES'&EPHEM_TYPE='OBSERVER'&CENTER='500@10'&START_TIME='2022-01-01'&STOP_TIME='2022-01-02'&STEP_SIZE='1d'&QUANTITIES='1,9'&CSV_FORMAT='YES'"
+        );
+
+        let res = Response::new(serde_json::json!({
+            "signature": {"source": "NASA/JPL Horizons API", "version": "1.1"},
+            "result": include_str!("fixtures/horizons_vectors.txt"),
+        }));
+        let table = HorizonsTable::from_response(&res).unwrap();
+        assert_eq!(table.columns.len(), 11);
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(
+            table.get(1, "Calendar Date (TDB)"),
+            Some("A.D. 2022-Jan-02 00:00:00.0000")
+        );
+
+        let vectors = table.vectors().unwrap();
+        assert_eq!(vectors[0].jd, 2459580.5);
+        assert_eq!(vectors[0].position[0], -1.233519580853036);
+        assert_eq!(vectors[1].rg, Some(1.583008937591985));
+        assert!(table.elements().is_err());
+
+        let observer = HorizonsTable::parse(
+            " Date__(UT)__HR:MN, , , R.A._____(ICRF)_____DEC, APmag, S-brt,\n\
+             ************\n\
+             $$SOE\n\
+             2022-Jan-01 00:00, , , 16 06 22.91 -20 47 49.5, 1.58, 4.25,\n\
+             $$EOE\n",
+        )
+        .unwrap();
+        assert_eq!(observer.get(0, "APmag"), Some("1.58"));
+
+        let res = Response::new(serde_json::json!({
+            "signature": {"source": "NASA/JPL Horizons API", "version": "1.1"},
+            "result": include_str!("fixtures/horizons_observer.txt"),
+        }));
+        let rows = HorizonsTable::from_response(&res)
+            .unwrap()
+            .observer()
+            .unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].datetime, "2022-Jan-01 00:00");
+        assert_eq!(rows[0].solar_presence, None);
+        assert_eq!(rows[1].lunar_presence.as_deref(), Some("m"));
+        let ra = (16.0 + 6.0 / 60.0 + 22.91 / 3600.0) * 15.0;
+        assert!((rows[0].ra.unwrap() - ra).abs() < 1e-9);
+        let dec = -(20.0 + 47.0 / 60.0 + 49.5 / 3600.0);
+        assert!((rows[0].dec.unwrap() - dec).abs() < 1e-9);
+        assert_eq!(rows[0].delta, Some(2.22458963572917));
+        assert_eq!(rows[1].deldot, Some(-10.6034516));
+        assert_eq!(rows[0].other["APmag"], "1.58");
+        assert_eq!(rows[0].other["/r"], "/L");
+        assert_eq!(rows[1].other["S-T-O"], "13.4642");
+        assert_eq!(rows[0].other.len(), 5);
+        assert!(table.observer().is_err());
+    }
+
+    #[test]
+    fn try_sb_ident_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = SbIdentClient::new();
+        assert!(base.url().is_err());
+        base.observer(Observer::Topocentric {
+            lat: 19.82,
+            lon: -155.47,
+            alt: 4.2,
+        });
+        base.obs_time("2022-01-01_12:00:00");
+        base.fov_center(150.0, -10.5);
+        base.fov_size(0.5, 0.25);
+        assert_eq!(
+            base.url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/sb_ident.api?lat=19.82&lon=-155.47&alt=4.2&obs-time=2022-01-01_12:00:00&fov-ra-center=10-00-00.00&fov-dec-center=M10-30-00.00&fov-ra-hwidth=0.5&fov-dec-hwidth=0.25"
+        );
+        base.fov_center(400.0, 0.0);
+        assert!(base.url().is_err());
+
+        let res = into_response(
+            r#"{"signature":{"source":"NASA/JPL Small-Body Identification API","version":"1.1"},
+            "n_first_pass":2,
+            "fields_first":["Object name","Astrometric RA (hh:mm:ss)","Astrometric Dec (dd mm'ss\")",
+                "Dist. from center RA (\")","Dist. from center Dec (\")","Dist. from center Norm (\")",
+                "Visual magnitude (V)","RA rate (\"/h)","Dec rate (\"/h)"],
+            "data_first_pass":[["433 Eros (A898 PA)","10:00:12.34","-10 29 01.2","182.","59.","191.","11.3","+52.1","-12.0"],
+                ["2012 TC4","09:59:50.00","-10 31 00.0","-150.","-60.","162.","19.9","+3.0","+1.0"]],
+            "n_second_pass":1,
+            "fields_second":["Object name","Astrometric RA (hh:mm:ss)","Astrometric Dec (dd mm'ss\")",
+                "Dist. from center RA (\")","Dist. from center Dec (\")","Dist. from center Norm (\")",
+                "Visual magnitude (V)","RA rate (\"/h)","Dec rate (\"/h)",
+                "Est. error RA (3-sigma \")","Est. error Dec (3-sigma \")"],
+            "data_second_pass":[["433 Eros (A898 PA)","10:00:12.31","-10 29 01.4","181.6","58.8","190.9","11.3","+52.08","-12.01","0.02","0.01"]]}"#,
+        )
+        .unwrap();
+        let bodies = SbIdentResult::from_response(&res).unwrap();
+        assert_eq!(bodies.first_pass.len(), 2);
+        assert_eq!(bodies.first_pass[0].name, "433 Eros (A898 PA)");
+        assert_eq!(bodies.first_pass[1].name, "2012 TC4");
+        assert_eq!(bodies.second_pass.len(), 1);
+        assert_eq!(bodies.second_pass[0].name, "433 Eros (A898 PA)");
+        assert_eq!(bodies.second_pass[0].err_ra, Some(0.02));
+        assert_eq!(bodies.first_pass[0].vmag, Some(11.3));
+        assert_eq!(bodies.first_pass[0].ra_rate, Some(52.1));
+        assert_eq!(bodies.first_pass[1].dist_norm, Some(162.0));
+        assert_eq!(bodies.first_pass[1].err_ra, None);
+    }
+
+    #[test]
+    fn try_periodic_orbits() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut base = PeriodicOrbitsClient::new();
+        base.sys("earth-moon");
+        base.family("lyapunov");
+        assert!(base.url().is_err());
+        base.libr(1);
+        base.jacobi(3.1, 3.2);
+        assert_eq!(
+            base.url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/periodic_orbits.api?sys=earth-moon&family=lyapunov&libr=1&jacobimin=3.1&jacobimax=3.2"
+        );
+        base.jacobi(3.2, 3.1);
+        assert!(base.url().is_err());
+
+        // An L1 Lyapunov orbit found by differential correction in the Earth-Moon system
+        let res = into_response(
+            r#"{"signature":{"source":"NASA/JPL Three-Body Periodic Orbits API","version":"1.0"},
+            "system":{"name":"earth-moon","mass_ratio":"1.215058560962404E-2","lunit":"389703.0","tunit":"382981.0"},
+            "family":"lyapunov","libration_point":"1","count":"1",
+            "fields":["x","y","z","vx","vy","vz","jacobi","period","stability"],
+            "data":[["0.8234","0","0","0","0.12623169403959228","0","3.174373283124692","2.742912084328241","1"]]}"#,
+        )
+        .unwrap();
+        let family = PeriodicOrbitFamily::from_response(&res).unwrap();
+        let mu = family.system.mass_ratio;
+        assert_eq!(family.libration_point, Some(1));
+        let orbit = &family.orbits[0];
+        assert!((jacobi_constant(mu, &orbit.state) - orbit.jacobi).abs() < 1e-12);
+        assert!(orbit.closure_error(mu, 20000) < 1e-6);
+
+        // Jacobi constant is conserved along the propagated arc
+        let states = orbit.propagate(mu, 20000);
+        assert_eq!(states.len(), 20001);
+        assert!((jacobi_constant(mu, &states[10000]) - orbit.jacobi).abs() < 1e-9);
+
+        // L4 is an equilibrium of the rotating frame
+        let l4 = [0.5 - mu, 3f64.sqrt() / 2.0, 0.0, 0.0, 0.0, 0.0];
+        let end = propagate_cr3bp(mu, l4, 10.0, 1000);
+        assert!((end[1000][0] - l4[0]).abs() < 1e-9 && (end[1000][1] - l4[1]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn try_mission_design_parsing() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let mut accessible = MissionDesignAccessible::new();
+        assert!(accessible.url().is_err());
+        accessible.limit(5);
+        accessible.crit(7);
+        accessible.year(String::from("2025,2026"));
+        assert!(accessible.url().is_err());
+        accessible.crit(1);
+        accessible.rdvz(true);
+        accessible.class(String::from("apo"));
+        assert_eq!(
+            accessible.url().unwrap(),
+            "https://ssd-api.jpl.nasa.gov/mdesign.api?lim=5&crit=1&year=2025,2026&rdvz=true&class=APO"
+        );
+        accessible.year(String::from("25"));
+        assert!(accessible.url().is_err());
+
+        let mut map = MissionDesignMap::new();
+        map.designation("2012 TC4");
+        map.mjd(58490);
+        map.span(3652);
+        map.tof(36, 10);
+        map.step(2);
+        assert!(map.url().is_err());
+        map.tof(10, 36);
+        assert!(map
+            .url()
+            .unwrap()
+            .ends_with("mjd0=58490&span=3652&tof-min=10&tof-max=36&step=2"));
+
+        let query = into_response(
+            r#"{"signature":{"version":"1.0"},
+            "object":{"des":"2012 TC4","fullname":"(2012 TC4)","spkid":"3614286",
+            "orbit_class":{"name":"Apollo","code":"APO"},"condition_code":"0","data_arc":"1854"},
+            "fields":["date0","MJD0","datef","MJDF","tof","c3_dep","vinf_dep","vinf_arr","dv_tot"],
+            "selectedMissions":[
+            ["2024-10-29",60612,"2025-04-14",60779,167,"12.3","3.51","2.2","6.1"],
+            ["2024-11-18",60632,"2025-03-05",60739,107,"20.1","4.48","1.9","5.4"]]}"#,
+        )
+        .unwrap();
+        let result = MissionDesignResult::from_response(&query).unwrap();
+        assert_eq!(result.object.orbit_class.as_deref(), Some("Apollo"));
+        assert_eq!(result.object.condition_code, Some(0));
+        assert_eq!(result.missions.len(), 2);
+        assert_eq!(result.missions[0].mjdf, 60779.0);
+        assert_eq!(result.lowest_delta_v().unwrap().mjd0, 60632.0);
+
+        let ranked = into_response(
+            r#"{"signature":{"version":"1.0"},"count":"1",
+            "fields":["name","class","H","condition_code","date0","MJD0","datef","MJDF","tof","c3_dep","vinf_arr","dv_tot"],
+            "data":[["2000 SG344","ATE","24.7","3","2028-04-26","61887","2028-10-05","62049","162","0.5","1.4","3.6"]]}"#,
+        )
+        .unwrap();
+        let targets = AccessibleTarget::from_response(&ranked).unwrap();
+        assert_eq!(targets[0].name, "2000 SG344");
+        assert_eq!(targets[0].h, Some(24.7));
+        assert_eq!(targets[0].mission.dv_tot, Some(3.6));
+    }
+
+    #[test]
+    fn try_porkchop_grid() {
+        use voyager_client::jpl::*;
+        use voyager_client::response::*;
+
+        let map = into_response(
+            r#"{"signature":{"version":"1.0"},"object":{"des":"2012 TC4"},
+            "dep_date":[60000,60010,60020],"tof":[100,200],
+            "dv_tot":[[6.0,8.0],[null,9.5],[5.0,7.2]],
+            "c3_dep":[[10,11],[20,21],[30,31]]}"#,
+        )
+        .unwrap();
+        let porkchop = Porkchop::from_response(&map).unwrap();
+        assert_eq!(porkchop.object.as_ref().unwrap().des, "2012 TC4");
+        assert_eq!(porkchop.value(PorkchopQuantity::C3Dep, 1, 0), Some(20.0));
+        assert_eq!(porkchop.delta_v(1, 0), None);
+
+        // grids are [departure][tof], a [tof][departure] grid is rejected rather than guessed
+        let transposed = into_response(
+            r#"{"dep_date":[60000,60010,60020],"tof":[100,200],
+            "c3_dep":[[10,20,30],[11,21,31]]}"#,
+        )
+        .unwrap();
+        assert!(Porkchop::from_response(&transposed).is_err());
+
+        let best = porkchop.minimum(PorkchopQuantity::DeltaV).unwrap();
+        assert_eq!(
+            (best.departure_mjd, best.tof, best.c3_dep),
+            (60020.0, 100.0, Some(30.0))
+        );
+        assert_eq!(porkchop.feasible(7.0).len(), 2);
+
+        let windows = porkchop.windows(7.0);
+        assert_eq!(windows.len(), 2);
+        assert_eq!(
+            (windows[0].open_mjd, windows[0].close_mjd),
+            (60000.0, 60000.0)
+        );
+        let windows = porkchop.windows(10.0);
+        assert_eq!(
+            (windows[0].open_mjd, windows[0].close_mjd),
+            (60000.0, 60020.0)
+        );
+        assert_eq!(windows[0].best.delta_v, Some(5.0));
+
+        let csv = porkchop.to_csv();
+        assert_eq!(csv.lines().count(), 7);
+        assert_eq!(csv.lines().nth(3), Some("60010,100,20,,,"));
+
+        let npy = porkchop.to_npy(PorkchopQuantity::DeltaV);
+        assert_eq!(&npy[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([npy[8], npy[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+        assert!(std::str::from_utf8(&npy[10..10 + header_len])
+            .unwrap()
+            .contains("'shape': (3, 2)"));
+        assert_eq!(npy.len(), 10 + header_len + 6 * 8);
+        let value = |i: usize| {
+            let start = 10 + header_len + i * 8;
+            f64::from_le_bytes(npy[start..start + 8].try_into().unwrap())
+        };
+        assert_eq!(value(0), 6.0);
+        assert!(value(2).is_nan());
+
+        let bad = into_response(r#"{"dep_date":[1,2],"tof":[1],"dv_tot":[[1,2,3]]}"#).unwrap();
+        assert!(Porkchop::from_response(&bad).is_err());
+    }
+
+    #[test]
+    fn try_time_conversions() {
+        use chrono::NaiveDate;
+        use voyager_client::jpl::*;
+        use voyager_client::time;
+
+        let epoch = NaiveDate::from_ymd(1858, 11, 17).and_hms(0, 0, 0);
+        assert_eq!(time::modified_julian_date(epoch), 0.0);
+        assert_eq!(time::julian_date(epoch), time::MJD_OFFSET);
+        assert_eq!(
+            time::from_julian_date(2451545.0).unwrap(),
+            NaiveDate::from_ymd(2000, 1, 1).and_hms(12, 0, 0)
+        );
+        let noon = NaiveDate::from_ymd(2022, 3, 4).and_hms_milli(12, 34, 56, 789);
+        assert_eq!(
+            time::from_modified_julian_date(time::modified_julian_date(noon)).unwrap(),
+            noon
+        );
+        assert_eq!(time::mjd_of_date(NaiveDate::from_ymd(2019, 1, 7)), 58490);
+
+        // 37 leap seconds + 32.184 s, within the 1.7 ms periodic TDB term
+        let offset = time::tdb_minus_utc(noon);
+        assert!((offset - 69.184).abs() < 0.002);
+        let y2k = NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0);
+        assert!((time::tdb_minus_utc(y2k) - 64.184).abs() < 0.002);
+        assert_eq!(time::tdb_to_utc(time::utc_to_tdb(noon)), noon);
+
+        assert_eq!(
+            time::parse_jpl_date("A.D. 2022-Mar-04 12:34:56.7890").unwrap(),
+            noon
+        );
+        assert_eq!(
+            time::parse_jpl_date("2022-03-04").unwrap(),
+            NaiveDate::from_ymd(2022, 3, 4).and_hms(0, 0, 0)
+        );
+        assert_eq!(time::to_jpl_format(noon), "2022-Mar-04 12:34");
+        assert!(time::parse_jpl_date("yesterday").is_err());
+
+        let mut map = MissionDesignMap::new();
+        map.designation("2012 TC4");
+        assert!(map.start_date(NaiveDate::from_ymd(1858, 11, 16)).is_err());
+        assert!(map.start_date(NaiveDate::from_ymd(1858, 11, 17)).is_ok());
+        map.start_date(NaiveDate::from_ymd(2019, 1, 7)).unwrap();
+        map.span(3652);
+        map.tof(10, 36);
+        map.step(2);
+        assert!(map.url().unwrap().contains("&mjd0=58490&"));
+    }
+
+    #[test]
+    fn try_query_encoding() {
+        use voyager_client::jpl::*;
+
+        assert_eq!(
+            MissionDesign::new().url(QueryType::DES, "2012 TC4"),
+            "https://ssd-api.jpl.nasa.gov/mdesign.api?des=2012%20TC4"
+        );
+
+        // reserved characters stay inside the value instead of adding parameters
+        let sbdb = SbdbClient::new();
+        assert_eq!(
+            sbdb.url(QueryType::SSTR, "eros&cov=mat#1+2"),
+            "https://ssd-api.jpl.nasa.gov/sbdb.api?sstr=eros%26cov%3Dmat%231%2B2"
+        );
+
+        let sentry = SentryClient::new();
+        assert_eq!(
+            sentry.object_url(&SentryTarget::Des(String::from("29075 (1950 DA)"))),
+            "https://ssd-api.jpl.nasa.gov/sentry.api?des=29075%20(1950%20DA)"
+        );
+    }
+
+    #[test]
+    fn try_tech_transfer_records() {
+        use voyager_client::response::*;
+        use voyager_client::tech::*;
+
+        let patents = into_response(
+            r#"{"results":[["5f2d8b0a","LEW-TOPS-99","Self-Sealing <span class=\"highlight\">Engine</span> Seals",
+            "Seals for rotating <span class=\"highlight\">engine</span> parts","LEW-19187-1","propulsion","","","",
+            "GRC","https://technology.nasa.gov/t2media/tops/img/LEW-TOPS-99/front.jpg","",1.7]],
+            "count":1,"total":21,"perpage":10,"page":2}"#,
+        )
+        .unwrap();
+        let page = TechPage::from_response(Collections::PatentIssued, &patents).unwrap();
+        assert_eq!((page.count, page.total, page.page), (1, 21, 2));
+        assert!(page.is_last());
+        match &page.records[0] {
+            TechRecord::Patent(patent) => {
+                assert_eq!(patent.case_number, "LEW-TOPS-99");
+                assert_eq!(patent.title, "Self-Sealing Engine Seals");
+                assert_eq!(patent.reference_number.as_deref(), Some("LEW-19187-1"));
+                assert_eq!(patent.center.as_deref(), Some("GRC"));
+            }
+            record => panic!("expected a patent, got {:?}", record),
+        }
+
+        let software = into_response(
+            r#"{"results":[["61a7","ARC-16133-1","Kepler Data Pipeline","Processing for Kepler",
+            "ARC-16133-1","Data Servers Processing and Handling","Open Source","","","ARC","","",0.9]],
+            "count":1,"total":40,"perpage":10,"page":0}"#,
+        )
+        .unwrap();
+        let page = TechPage::from_response(Collections::Software, &software).unwrap();
+        assert!(!page.is_last());
+        match &page.records[0] {
+            TechRecord::Software(software) => {
+                assert_eq!(software.release_type.as_deref(), Some("Open Source"));
+                assert_eq!(
+                    software.category.as_deref(),
+                    Some("Data Servers Processing and Handling")
+                );
+            }
+            record => panic!("expected software, got {:?}", record),
+        }
+
+        let empty =
+            into_response(r#"{"results":[],"count":0,"total":0,"perpage":10,"page":0}"#).unwrap();
+        let page = TechPage::from_response(Collections::Spinoff, &empty).unwrap();
+        assert!(page.records.is_empty() && page.is_last());
+
+        let far = TechPage {
+            count: 0,
+            total: u32::MAX,
+            perpage: u32::MAX,
+            page: u32::MAX - 1,
+            records: Vec::new(),
+        };
+        assert!(far.is_last());
+    }
+
+    #[test]
+    fn try_insight_series() {
+        use voyager_client::insight::*;
+        use voyager_client::response::*;
+
+        let weather = into_response(
+            r#"{"sol_keys":["259","260","261"],
+            "259":{"AT":{"av":-71.233,"ct":326642,"mn":-101.024,"mx":-27.149},
+            "HWS":{"av":4.35,"ct":154146,"mn":0.156,"mx":17.617},
+            "PRE":{"av":761.006,"ct":163012,"mn":742.1498,"mx":780.3891},
+            "First_UTC":"2019-08-19T08:03:59Z","Last_UTC":"2019-08-20T08:43:34Z","Season":"spring",
+            "WD":{"most_common":{"compass_degrees":202.5,"compass_point":"SSW","compass_right":-0.38,"compass_up":-0.92,"ct":28320},
+            "9":{"compass_degrees":202.5,"compass_point":"SSW","compass_right":-0.38,"compass_up":-0.92,"ct":28320},
+            "1":{"compass_degrees":22.5,"compass_point":"NNE","compass_right":0.38,"compass_up":0.92,"ct":3}}},
+            "260":{"AT":{"av":-71.0,"ct":10,"mn":-100.0,"mx":-28.0},
+            "PRE":{"av":759.2,"ct":162998,"mn":741.0,"mx":779.8},"Season":"spring"},
+            "261":{"AT":{"av":-70.0,"ct":5,"mn":-99.0,"mx":-27.0},"Season":"spring"},
+            "validity_checks":{"259":{"AT":{"valid":true},"HWS":{"valid":true},"PRE":{"valid":true},"WD":{"valid":true}},
+            "260":{"AT":{"sol_hours_with_data":[1,2],"valid":false},"PRE":{"valid":true}},
+            "261":{"AT":{"valid":false}},
+            "sol_hours_required":18,"sols_checked":["259","260","261"]}}"#,
+        )
+        .unwrap();
+        let series = InsightSeries::from_response(&weather).unwrap();
+
+        // sol 261 has no valid sensor left
+        assert_eq!(series.sols.len(), 2);
+        let first = &series.sols[0];
+        assert_eq!(first.sol, 259);
+        assert_eq!(first.at.as_ref().unwrap().mn, -101.024);
+        assert_eq!(first.wd.len(), 2);
+        assert_eq!(first.wd[0].compass_point, "NNE");
+        assert_eq!(first.wd_most_common.as_ref().unwrap().ct, 28320);
+        assert_eq!(first.first_utc.unwrap().to_string(), "2019-08-19 08:03:59");
+        assert!(series.sols[1].at.is_none());
+        assert_eq!(series.sols[1].pre.as_ref().unwrap().av, 759.2);
+
+        let csv = series.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0].split(',').count(), 17);
+        assert!(
+            lines[1].starts_with("259,2019-08-19T08:03:59Z,2019-08-20T08:43:34Z,spring,-71.233,")
+        );
+        assert!(lines[1].ends_with(",SSW"));
+        assert_eq!(lines[2], "260,,,spring,,,,,,,,,759.2,741,779.8,162998,");
+    }
+
+    #[test]
+    fn try_eonet_parsing() {
+        use voyager_client::eonet::*;
+        use voyager_client::response::*;
+
+        let mut base = EonetClient::new();
+        base.status(EventStatus::All);
+        base.category("severeStorms,volcanoes");
+        base.bbox(-130.0, 50.0, -60.0, 20.0);
+        base.start("2022-01-01");
+        base.end("2022-01-31");
+        base.limit(5);
+        base.magnitude("mag_kts", Some(50.0), None);
+        assert_eq!(
+            base.events_url().unwrap(),
+            "https://eonet.gsfc.nasa.gov/api/v3/events?status=all&category=severeStorms,volcanoes&bbox=-130,50,-60,20&start=2022-01-01&end=2022-01-31&limit=5&magID=mag_kts&magMin=50"
+        );
+        assert!(base
+            .geojson_url()
+            .unwrap()
+            .starts_with("https://eonet.gsfc.nasa.gov/api/v3/events/geojson?"));
+        base.days(10);
+        assert!(base.events_url().is_err());
+
+        let mut base = EonetClient::new();
+        base.bbox(-130.0, 20.0, -60.0, 50.0);
+        assert!(base.events_url().is_err());
+
+        let events = into_response(
+            r#"{"title":"EONET Events","events":[
+            {"id":"EONET_5972","title":"Tropical Cyclone Cody","description":null,
+            "link":"https://eonet.gsfc.nasa.gov/api/v3/events/EONET_5972","closed":"2022-01-17T00:00:00Z",
+            "categories":[{"id":"severeStorms","title":"Severe Storms"}],
+            "sources":[{"id":"JTWC","url":"https://www.metoc.navy.mil/jtwc/products/sh0622.tcw"}],
+            "geometry":[
+            {"magnitudeValue":35.00,"magnitudeUnit":"kts","date":"2022-01-10T06:00:00Z","type":"Point","coordinates":[177.5,-15.9]},
+            {"magnitudeValue":50.00,"magnitudeUnit":"kts","date":"2022-01-11T12:00:00Z","type":"Point","coordinates":[178.9,-19.3]}]},
+            {"id":"EONET_5980","title":"Iceberg D28A","description":null,
+            "link":"https://eonet.gsfc.nasa.gov/api/v3/events/EONET_5980","closed":null,
+            "categories":[{"id":"seaLakeIce","title":"Sea and Lake Ice"}],"sources":[],
+            "geometry":[{"magnitudeValue":null,"magnitudeUnit":null,"date":"2022-01-20T00:00:00Z","type":"Polygon",
+            "coordinates":[[[-50.0,-70.0],[-49.0,-70.0],[-49.0,-71.0],[-50.0,-70.0]]]}]}]}"#,
+        )
+        .unwrap();
+        let events = Event::from_response(&events).unwrap();
+        assert_eq!(events.len(), 2);
+        assert!(!events[0].is_open() && events[1].is_open());
+        assert_eq!(events[0].latest().unwrap().magnitude_value, Some(50.0));
+        let track = events[0].track();
+        assert_eq!(track[1].0.to_string(), "2022-01-11 12:00:00");
+        assert_eq!((track[1].1, track[1].2), (178.9, -19.3));
+        match &events[1].geometry[0].shape {
+            Shape::Polygon(rings) => assert_eq!(rings[0].len(), 4),
+            shape => panic!("expected a polygon, got {:?}", shape),
+        }
+        assert!(events[1].track().is_empty());
+    }
+
+    #[test]
+    fn try_epic_parsing() {
+        use voyager_client::epic::*;
+        use voyager_client::response::*;
+
+        let metadata = into_response(
+            r#"[{"identifier":"20190530011359","caption":"This image was taken by NASA's EPIC camera onboard the NOAA DSCOVR spacecraft",
+            "image":"epic_1b_20190530011359","version":"02","date":"2019-05-30 01:09:10",
+            "centroid_coordinates":{"lat":11.030273,"lon":157.258301},
+            "dscovr_j2000_position":{"x":-1253844.265141,"y":-672148.289185,"z":-127497.713356},
+            "lunar_j2000_position":{"x":-370014.063633,"y":-93153.582428,"z":-5839.301611},
+            "sun_j2000_position":{"x":-53651127.849604,"y":-124747637.035005,"z":-54079311.071106},
+            "attitude_quaternions":{"q0":-0.325006,"q1":0.0025,"q2":0.00015,"q3":0.945712},
+            "coords":{}}]"#,
+        )
+        .unwrap();
+        let images = EpicImage::from_response(&metadata).unwrap();
+        let image = &images[0];
+        assert_eq!(image.centroid_coordinates.lon, 157.258301);
+        assert_eq!(image.attitude_quaternions.q3, 0.945712);
+        assert!((image.dscovr_distance() - 1428343.25).abs() < 0.01);
+        assert_eq!(image.datetime().unwrap().to_string(), "2019-05-30 01:09:10");
+
+        let mut base = EpicClient::new();
+        assert_eq!(
+            base.image_url(image, ImageFormat::Png).unwrap(),
+            "https://epic.gsfc.nasa.gov/archive/natural/2019/05/30/png/epic_1b_20190530011359.png"
+        );
+        base.collection(EpicCollection::Enhanced);
+        assert_eq!(
+            base.image_url(image, ImageFormat::Thumbs).unwrap(),
+            "https://epic.gsfc.nasa.gov/archive/enhanced/2019/05/30/thumbs/epic_1b_20190530011359.jpg"
+        );
+        assert!(base.query_date("2019-5-30x").is_err());
+    }
+
+    #[test]
+    fn try_mars_photos_parsing() {
+        use voyager_client::mars_photos::*;
+        use voyager_client::response::*;
+
+        let res = into_response(
+            r#"{"photos":[{"id":102693,"sol":1000,
+            "camera":{"id":20,"name":"FHAZ","rover_id":5,"full_name":"Front Hazard Avoidance Camera"},
+            "img_src":"http://mars.jpl.nasa.gov/msl-raw-images/proj/msl/redops/ods/surface/sol/01000/opgs/edr/fcam/FLB_486265257EDR_F0481570FHAZ00323M_.JPG",
+            "earth_date":"2015-05-30",
+            "rover":{"id":5,"name":"Curiosity","landing_date":"2012-08-06","launch_date":"2011-11-26","status":"active"}}]}"#,
+        )
+        .unwrap();
+        let photos = Photo::from_response(&res, "photos").unwrap();
+        assert_eq!(photos[0].camera.name, "FHAZ");
+        assert_eq!(photos[0].rover.name, "Curiosity");
+        assert_eq!(photos[0].rover.max_sol, None);
+        assert!(Photo::from_response(&res, "latest_photos").is_err());
+
+        let res = into_response(
+            r#"{"photo_manifest":{"name":"Curiosity","landing_date":"2012-08-06","launch_date":"2011-11-26",
+            "status":"active","max_sol":1000,"max_date":"2015-05-30","total_photos":4156,"photos":[
+            {"sol":0,"earth_date":"2012-08-06","total_photos":3702,"cameras":["CHEMCAM","FHAZ","MARDI","RHAZ"]},
+            {"sol":1,"earth_date":"2012-08-07","total_photos":16,"cameras":["MAHLI","MAST","NAVCAM"]},
+            {"sol":2,"earth_date":"2012-08-08","total_photos":438,"cameras":["FHAZ","NAVCAM"]}]}}"#,
+        )
+        .unwrap();
+        let manifest = Manifest::from_response(&res).unwrap();
+        assert_eq!(
+            manifest.cameras_by_sol()[&1],
+            vec!["MAHLI", "MAST", "NAVCAM"]
+        );
+        assert_eq!(manifest.sols_with_camera("navcam"), vec![1, 2]);
+        assert_eq!(manifest.sols_with_camera("FHAZ"), vec![0, 2]);
+
+        let mut base = MarsPhotosClient::new(MarsRover::Curiosity);
+        assert!(base.photos_url().is_err());
+        base.sol(1000);
+        base.camera("FHAZ");
+        base.page(2);
+        assert_eq!(
+            base.photos_url().unwrap(),
+            "https://api.nasa.gov/mars-photos/api/v1/rovers/curiosity/photos?sol=1000&camera=fhaz&page=2"
+        );
+        base.earth_date("2015-06-03");
+        assert!(base.photos_url().is_err());
+
+        let mut base = MarsPhotosClient::new(MarsRover::Perseverance);
+        base.earth_date("2021-02-30");
+        assert!(base.photos_url().is_err());
+        base.earth_date("2021-03-01");
+        assert_eq!(
+            base.photos_url().unwrap(),
+            "https://api.nasa.gov/mars-photos/api/v1/rovers/perseverance/photos?earth_date=2021-03-01"
+        );
+    }
+
+    #[test]
+    fn try_image_library_parsing() {
+        use voyager_client::images::*;
+        use voyager_client::response::*;
+
+        let res = into_response(
+            r#"{"collection":{"version":"1.0","href":"http://images-api.nasa.gov/search?q=apollo%2011",
+            "items":[{"href":"https://images-assets.nasa.gov/image/as11-40-5874/collection.json",
+            "data":[{"center":"JSC","title":"Apollo 11 Mission image - Astronaut Edwin Aldrin poses beside th",
+            "nasa_id":"as11-40-5874","date_created":"1969-07-20T00:00:00Z","keywords":["APOLLO 11"],
+            "media_type":"image","description":"Astronaut Edwin Aldrin poses beside the U.S. flag"}],
+            "links":[{"href":"https://images-assets.nasa.gov/image/as11-40-5874/as11-40-5874~thumb.jpg","rel":"preview","render":"image"}]}],
+            "metadata":{"total_hits":336},
+            "links":[{"rel":"next","prompt":"Next","href":"http://images-api.nasa.gov/search?q=apollo%2011&page=2"}]}}"#,
+        )
+        .unwrap();
+        let results = SearchResults::from_response(&res).unwrap();
+        assert_eq!(results.total_hits, 336);
+        assert_eq!(
+            results.next.as_deref(),
+            Some("http://images-api.nasa.gov/search?q=apollo%2011&page=2")
+        );
+        assert_eq!(results.prev, None);
+        let item = &results.items[0];
+        assert_eq!(item.info().unwrap().nasa_id, "as11-40-5874");
+        assert_eq!(item.info().unwrap().keywords, vec!["APOLLO 11"]);
+        assert!(item.preview().unwrap().ends_with("~thumb.jpg"));
+
+        let res = into_response(
+            r#"{"collection":{"version":"1.0","href":"http://images-api.nasa.gov/asset/as11-40-5874","items":[
+            {"href":"http://images-assets.nasa.gov/image/as11-40-5874/as11-40-5874~large.jpg"},
+            {"href":"http://images-assets.nasa.gov/image/as11-40-5874/as11-40-5874~thumb.jpg"},
+            {"href":"http://images-assets.nasa.gov/image/as11-40-5874/metadata.json"}]}}"#,
+        )
+        .unwrap();
+        let asset = Asset::from_response(&res).unwrap();
+        assert_eq!(asset.rendition(Rendition::Original), None);
+        assert_eq!(
+            asset.largest(),
+            Some("http://images-assets.nasa.gov/image/as11-40-5874/as11-40-5874~large.jpg")
+        );
+        assert!(asset
+            .rendition(Rendition::Thumb)
+            .unwrap()
+            .ends_with("~thumb.jpg"));
+
+        let mut base = ImageLibraryClient::new();
+        assert!(base.search_url().is_err());
+        base.q("apollo 11");
+        base.keywords(&["moon", "EVA"]);
+        base.media_types(&[MediaType::Image, MediaType::Video]);
+        base.year_start(1969);
+        assert_eq!(
+            base.search_url().unwrap(),
+            "https://images-api.nasa.gov/search?q=apollo%2011&keywords=moon,EVA&media_type=image,video&year_start=1969"
+        );
+        base.year_end(1968);
+        assert!(base.search_url().is_err());
+    }
+
+    #[test]
+    fn try_exoplanet_tables() {
+        use voyager_client::exoplanet::*;
+
+        let base = ExoplanetClient::new();
+        assert_eq!(
+            base.sync_url("select pl_name from ps where disc_year > 2020", TapFormat::Csv)
+                .unwrap(),
+            "https://exoplanetarchive.ipac.caltech.edu/TAP/sync?query=select%20pl_name%20from%20ps%20where%20disc_year%20%3E%202020&format=csv"
+        );
+        assert!(base.sync_url("  ", TapFormat::Json).is_err());
+
+        let adql = discovery_query("Radial Velocity", Some(1995));
+        assert!(adql.contains("where discoverymethod = 'Radial Velocity' and disc_year = 1995"));
+        assert!(host_query("Barnard's star").contains("hostname = 'Barnard''s star'"));
+
+        let planets: Vec<Planet> = serde_json::from_str(
+            r#"[{"pl_name":"Kepler-11 b","hostname":"Kepler-11","discoverymethod":"Transit","disc_year":2010,
+            "disc_facility":"Kepler","pl_orbper":10.3039,"pl_orbsmax":0.091,"pl_rade":1.8,"pl_bmasse":1.9,
+            "pl_eqt":900,"st_teff":5663,"sy_dist":646.346,"ra":297.1150958,"dec":41.9090304}]"#,
+        )
+        .unwrap();
+        assert_eq!(planets[0].disc_year, Some(2010));
+        assert_eq!(planets[0].pl_eqt, Some(900.0));
+
+        let table = Table::from_csv(
+            "pl_name,hostname,pl_rade,disc_facility\n\
+             Kepler-11 b,Kepler-11,1.8,Kepler\n\
+             \"HD 209458 b\",HD 209458,,\"Multiple Observatories, \"\"HST\"\"\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            table.columns,
+            vec!["pl_name", "hostname", "pl_rade", "disc_facility"]
+        );
+        assert_eq!(table.get_f64(0, "pl_rade"), Some(1.8));
+        assert_eq!(table.get(1, "pl_rade"), None);
+        assert_eq!(
+            table.get(1, "disc_facility"),
+            Some("Multiple Observatories, \"HST\"")
+        );
+        assert_eq!(
+            table.column("pl_name").unwrap(),
+            vec![Some("Kepler-11 b"), Some("HD 209458 b")]
+        );
+        assert!(Table::from_csv("a,b\n1\n").is_err());
+    }
+
+    #[test]
+    fn try_techport_projects() {
+        use chrono::NaiveDate;
+        use voyager_client::response::*;
+        use voyager_client::techport::*;
+
+        let res = into_response(
+            r#"{"projects":[{"projectId":17792,"lastUpdated":"2024-1-12"},
+            {"projectId":"93145","lastUpdated":"2024-02-03"},{"projectId":94012}],"totalCount":3}"#,
+        )
+        .unwrap();
+        let updated = ProjectSummary::from_response(&res).unwrap();
+        assert_eq!(updated.len(), 3);
+        assert_eq!(updated[1].project_id, 93145);
+        assert_eq!(updated[2].last_updated, None);
+        let since = NaiveDate::from_ymd(2024, 1, 1);
+        assert_eq!(next_since(since, &updated), NaiveDate::from_ymd(2024, 2, 3));
+        assert_eq!(next_since(since, &[]), since);
+
+        let nested = into_response(
+            r#"{"projects":{"totalCount":1,"projects":[{"id":4949,"lastUpdated":"2014-11-06"}]}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ProjectSummary::from_response(&nested).unwrap()[0].project_id,
+            4949
+        );
+
+        let res = into_response(
+            r#"{"project":{"projectId":93145,"title":"Lunar Surface Power","statusDescription":"Active",
+            "description":"<p>Power for the lunar night</p>","trlBegin":3,"trlCurrent":"4","trlEnd":6,
+            "startDateString":"Oct 2022","endDateString":"Sep 2025","lastUpdated":"2024-2-3",
+            "leadOrganization":{"organizationName":"Glenn Research Center","organizationType":"NASA_Center","city":"Cleveland"},
+            "supportingOrganizations":[{"organizationName":"Ohio State University","organizationType":"Academia"},{"acronym":"?"}]}}"#,
+        )
+        .unwrap();
+        let project = Project::from_response(&res).unwrap();
+        assert_eq!(project.status.as_deref(), Some("Active"));
+        assert_eq!(
+            (project.trl_begin, project.trl_current, project.trl_end),
+            (Some(3), Some(4), Some(6))
+        );
+
+        let odd_trl = into_response(
+            r#"{"project":{"projectId":93145,"title":"Lunar Surface Power","trlBegin":-1,"trlCurrent":12,"trlEnd":"4.5"}}"#,
+        )
+        .unwrap();
+        let odd = Project::from_response(&odd_trl).unwrap();
+        assert_eq!(
+            (odd.trl_begin, odd.trl_current, odd.trl_end),
+            (None, None, None)
+        );
+        for id in [r#"-3"#, r#"17792.5"#, r#""abc""#] {
+            let res =
+                into_response(&format!(r#"{{"projects":[{{"projectId":{}}}]}}"#, id)).unwrap();
+            assert!(ProjectSummary::from_response(&res).is_err(), "{}", id);
+        }
+        assert_eq!(project.start_date.as_deref(), Some("Oct 2022"));
+        assert_eq!(
+            project.lead_organization.unwrap().city.as_deref(),
+            Some("Cleveland")
+        );
+        assert_eq!(project.supporting_organizations.len(), 1);
+
+        let base = TechPortClient::new();
+        assert_eq!(
+            base.projects_url("2024-01-01").unwrap(),
+            "https://api.nasa.gov/techport/api/projects?updatedSince=2024-01-01"
+        );
+        assert!(base.projects_url("01/01/2024").is_err());
+    }
+
+    #[test]
+    fn try_tle_sgp4() {
+        use chrono::{Duration, NaiveDate};
+        use voyager_client::tle::*;
+
+        let line1 = "1 00005U 58002B   00179.78495062  .00000023  00000-0  28098-4 0  4753";
+        let line2 = "2 00005  34.2682 348.7242 1859667 331.7664  19.3264 10.82419157413667";
+        assert_eq!(checksum(line1), 3);
+        assert_eq!(checksum(line2), 7);
+
+        let tle = Tle::parse(line1, line2).unwrap();
+        assert_eq!(tle.satellite_number, 5);
+        assert_eq!(tle.international_designator, "58002B");
+        assert_eq!(tle.bstar, 0.28098e-4);
+        assert_eq!(tle.eccentricity, 0.1859667);
+        assert_eq!(tle.revolution_number, 41366);
+        assert_eq!(
+            tle.epoch,
+            NaiveDate::from_ymd(2000, 6, 27).and_hms_micro(18, 50, 19, 733568)
+        );
+
+        let corrupted = line2.replace("34.2682", "34.2683");
+        assert!(Tle::parse(line1, &corrupted).is_err());
+        assert!(Tle::parse(line2, line1).is_err());
+        let with_checksum = |line: String| format!("{}{}", line, checksum(&line));
+        let bad_set = with_checksum(format!("{} 4X5", &line1[..64]));
+        assert!(Tle::parse(&bad_set, line2).is_err());
+        let bad_revolution = with_checksum(format!("{}4136X", &line2[..63]));
+        assert!(Tle::parse(line1, &bad_revolution).is_err());
+
+        // Vallado's verification values for Vanguard 1, km and km/s
+        let sgp4 = tle.sgp4().unwrap();
+        let expected = [
+            (
+                0.0,
+                [7022.46529266, -1400.08296755, 0.03995155],
+                [1.893841015, 6.405893759, 4.534807250],
+            ),
+            (
+                360.0,
+                [-7154.03120202, -3783.17682504, -3536.19412294],
+                [4.741887409, -4.151817765, -2.093935425],
+            ),
+            (
+                4320.0,
+                [-9060.47373569, 4658.70952502, 813.68673153],
+                [-2.232832783, -4.110453490, -3.157345433],
+            ),
+        ];
+        for (minutes, position, velocity) in expected {
+            let state = sgp4.propagate(minutes).unwrap();
+            for i in 0..3 {
+                assert!(
+                    (state.position[i] - position[i]).abs() < 1e-6,
+                    "{:?}",
+                    state
+                );
+                assert!(
+                    (state.velocity[i] - velocity[i]).abs() < 1e-9,
+                    "{:?}",
+                    state
+                );
+            }
+        }
+        let state = sgp4
+            .propagate_to(tle.epoch + Duration::minutes(360))
+            .unwrap();
+        assert!((state.minutes - 360.0).abs() < 1e-9);
+
+        let cached = format!("VANGUARD 1\n{}\n{}\n\n{}\n{}\n", line1, line2, line1, line2);
+        let tles = Tle::parse_lines(&cached).unwrap();
+        assert_eq!(tles.len(), 2);
+        assert_eq!(tles[0].name.as_deref(), Some("VANGUARD 1"));
+        assert_eq!(tles[1].name, None);
+
+        let track = sgp4
+            .ground_track(
+                tle.epoch,
+                tle.epoch + Duration::minutes(30),
+                Duration::minutes(10),
+            )
+            .unwrap();
+        assert_eq!(track.len(), 4);
+        // on the equator at epoch, |r| = 7160.67 km
+        assert!(track[0].latitude.abs() < 0.01);
+        assert!((track[0].altitude - (7160.67 - 6378.137)).abs() < 0.1);
+        for point in track {
+            // geodetic latitudes run slightly above the inclination
+            assert!(point.latitude.abs() < 34.5, "{:?}", point);
+            assert!(point.altitude > 600.0 && point.altitude < 3900.0);
+        }
+
+        let mut base = TleClient::new();
+        base.page_size(101);
+        assert!(base.search_url("ISS").is_err());
+        base.page_size(50);
+        assert_eq!(
+            base.search_url("ISS (ZARYA)").unwrap(),
+            "https://tle.ivanstanojevic.me/api/tle?search=ISS%20(ZARYA)&page-size=50"
+        );
+    }
+
+    #[test]
+    fn try_earth_assets() {
+        use chrono::NaiveDate;
+        use voyager_client::earth::*;
+        use voyager_client::response::*;
+
+        let single = into_response(
+            r#"{"date":"2014-02-04T03:30:01.210000","id":"LC8_L1T_TOA/LC81270592014035LGN00",
+            "resource":{"dataset":"LC8_L1T_TOA","planet":"earth"},"service_version":"v5000",
+            "url":"https://earthengine.googleapis.com/v1alpha/projects/earthengine-public/thumbnails/abc:getPixels"}"#,
+        )
+        .unwrap();
+        let assets = Asset::from_response(&single).unwrap();
+        assert_eq!(assets[0].id, "LC8_L1T_TOA/LC81270592014035LGN00");
+        assert_eq!(
+            assets[0].datetime().unwrap().to_string(),
+            "2014-02-04 03:30:01.210"
+        );
+
+        let list = into_response(
+            r#"{"count":3,"results":[{"date":"2014-01-03T03:30:22","id":"LC8_L1T_TOA/LC81270592014003LGN00"},
+            {"date":"2014-02-04T03:30:01.210000","id":"LC8_L1T_TOA/LC81270592014035LGN00"},
+            {"date":"2014-02-20T03:29:47","id":"LC8_L1T_TOA/LC81270592014051LGN00"}]}"#,
+        )
+        .unwrap();
+        let assets = Asset::from_response(&list).unwrap();
+        let february = NaiveDate::from_ymd(2014, 2, 15);
+        assert_eq!(
+            closest_in_month(&assets, february).unwrap().id,
+            "LC8_L1T_TOA/LC81270592014051LGN00"
+        );
+        assert_eq!(
+            closest_in_month(&assets, NaiveDate::from_ymd(2014, 3, 15)),
+            None
+        );
+        assert!(
+            Asset::from_response(&into_response(r#"{"msg":"no assets"}"#).unwrap())
+                .unwrap()
+                .is_empty()
+        );
+        for error in [
+            r#"{"error":{"code":"API_KEY_INVALID","message":"An invalid api_key was supplied."}}"#,
+            r#"{"error":{"code":"OVER_RATE_LIMIT","message":"You have exceeded your rate limit."}}"#,
+            r#"{"code":500,"msg":"Internal Service Error"}"#,
+            r#"{}"#,
+        ] {
+            assert!(Asset::from_response(&into_response(error).unwrap()).is_err());
+        }
+
+        let targets = months(
+            NaiveDate::from_ymd(2013, 11, 20),
+            NaiveDate::from_ymd(2014, 2, 1),
+        )
+        .unwrap();
+        assert_eq!(
+            targets,
+            vec![
+                NaiveDate::from_ymd(2013, 11, 15),
+                NaiveDate::from_ymd(2013, 12, 15),
+                NaiveDate::from_ymd(2014, 1, 15),
+                NaiveDate::from_ymd(2014, 2, 15),
+            ]
+        );
+        assert!(months(
+            NaiveDate::from_ymd(2014, 2, 1),
+            NaiveDate::from_ymd(2014, 1, 1)
+        )
+        .is_err());
+
+        let mut base = EarthClient::new();
+        assert!(base.assets_url().is_err());
+        base.location(1.5, 100.75);
+        base.date("2014-02-01");
+        base.dim(0.15);
+        base.cloud_score(true);
+        assert_eq!(
+            base.imagery_url().unwrap(),
+            "https://api.nasa.gov/planetary/earth/imagery?lon=100.75&lat=1.5&date=2014-02-01&dim=0.15&cloud_score=True"
+        );
+        assert_eq!(
+            base.assets_url().unwrap(),
+            "https://api.nasa.gov/planetary/earth/assets?lon=100.75&lat=1.5&date=2014-02-01&dim=0.15"
+        );
+        base.location(91.0, 0.0);
+        assert!(base.assets_url().is_err());
+    }
+
+    #[test]
+    fn try_osdr_parsing() {
+        use voyager_client::osdr::*;
+        use voyager_client::response::*;
+
+        let res = into_response(
+            r#"{"took":3,"hits":{"total":{"value":42,"relation":"eq"},"hits":[
+            {"_index":"cgene","_id":"OSD-87","_source":{"Accession":"OSD-87","Study Title":"Rodent Research-1 (RR1) NASA Validation Flight",
+            "Study Description":"Mouse liver transcriptomics","organism":"Mus musculus","Project Type":"Spaceflight Study"}},
+            {"_index":"nih_geo_gse","_id":"GSE12345","_source":{"Accession":"GSE12345","organism":["Homo sapiens","Mus musculus"]}}]}}"#,
+        )
+        .unwrap();
+        let results = SearchResults::from_response(&res).unwrap();
+        assert_eq!(results.total, 42);
+        assert_eq!(results.datasets[0].accession.as_deref(), Some("OSD-87"));
+        assert_eq!(
+            results.datasets[0].project_type.as_deref(),
+            Some("Spaceflight Study")
+        );
+        assert_eq!(
+            results.datasets[1].organism.as_deref(),
+            Some("Homo sapiens, Mus musculus")
+        );
+        assert_eq!(results.datasets[1].title, None);
+
+        let res = into_response(
+            r#"{"hits":1,"input":"87","success":true,"studies":{"OSD-87":{"file_count":2,"study_files":[
+            {"category":"Study Metadata Files","file_name":"OSD-87_metadata_OSD-87-ISA.zip","file_size":21873,
+            "remote_url":"/geode-py/ws/studies/OSD-87/download?source=datamanager&file=OSD-87_metadata_OSD-87-ISA.zip"},
+            {"category":"Raw Sequence Data","file_name":"GLDS-87_rna_seq_Mmus_C57-6J_LVR_GC_I_Rep1_M25_R1_raw.fastq.gz","file_size":1453028741,
+            "remote_url":"https://example.org/GLDS-87_R1_raw.fastq.gz"}],"study_file_count":2}}}"#,
+        )
+        .unwrap();
+        let files = StudyFile::from_response(&res).unwrap();
+        assert_eq!(files.len(), 2);
+        assert_eq!(files[0].study, "OSD-87");
+        assert_eq!(
+            files[0].url(),
+            "https://osdr.nasa.gov/geode-py/ws/studies/OSD-87/download?source=datamanager&file=OSD-87_metadata_OSD-87-ISA.zip"
+        );
+        assert_eq!(
+            files[1].url(),
+            "https://example.org/GLDS-87_R1_raw.fastq.gz"
+        );
+
+        let csv = manifest_csv(&files);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "study,file_name,category,file_size,url");
+        assert!(lines[2].starts_with(
+            "OSD-87,GLDS-87_rna_seq_Mmus_C57-6J_LVR_GC_I_Rep1_M25_R1_raw.fastq.gz,Raw Sequence Data,1453028741,"
+        ));
+
+        let mut base = OsdrClient::new();
+        base.term("mouse liver");
+        base.sources(&[DataSource::GeneLab, DataSource::NihGeo]);
+        base.filter("organism", "Mus musculus");
+        base.filter("Project Type", "Spaceflight Study");
+        base.from(20);
+        base.size(10);
+        assert_eq!(
+            base.search_url().unwrap(),
+            "https://osdr.nasa.gov/osdr/data/search?term=mouse%20liver&type=cgene,nih_geo_gse&ffield=organism&fvalue=Mus%20musculus&ffield=Project%20Type&fvalue=Spaceflight%20Study&from=20&size=10"
+        );
+        assert_eq!(
+            base.files_url(87),
+            "https://osdr.nasa.gov/osdr/data/osd/files/87"
+        );
+    }
+}